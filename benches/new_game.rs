@@ -0,0 +1,59 @@
+//! Benchmarks of how long it takes to start a new game
+//!
+//! Run with `cargo bench`. Word lists are indexed the first time they are used, so
+//! every benchmark after the first measures the cost of a new game with the shared
+//! index already built, while `index_word_list` measures building an index.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+use tui_wordle::dictionary::{get_dictionaries, load_word_list, Dictionary, WordIndex};
+use tui_wordle::game::{GameData, GameOptions};
+
+/// Dictionaries benchmarked, from the smallest word list to the largest
+const DICTIONARIES: [(&str, u8); 3] = [("Wordle", 5), ("Scrabble", 5), ("French", 5)];
+
+/// Finds a built-in dictionary, if it is available in this build
+fn dictionary(name: &str, length: u8) -> Option<Arc<Dictionary>> {
+    get_dictionaries()
+        .into_iter()
+        .find(|d| d.name == name && d.length == length)
+}
+
+fn new_game(c: &mut Criterion) {
+    for (name, length) in DICTIONARIES {
+        let Some(dictionary) = dictionary(name, length) else { continue };
+
+        let options = GameOptions {
+            word_length: length as u16,
+            dictionary,
+            ..GameOptions::default()
+        };
+
+        c.bench_function(&format!("new_game/{}_{}", name, length), |b| {
+            b.iter(|| GameData::new(black_box(&options)))
+        });
+    }
+}
+
+fn is_valid_word(c: &mut Criterion) {
+    for (name, length) in DICTIONARIES {
+        let Some(dictionary) = dictionary(name, length) else { continue };
+
+        c.bench_function(&format!("is_valid_word/{}_{}", name, length), |b| {
+            b.iter(|| dictionary.is_valid_word(black_box("zzzzz")))
+        });
+    }
+}
+
+fn index_word_list(c: &mut Criterion) {
+    for file in ["wordle.txt", "scrabble.txt", "french.txt"] {
+        let Ok(lines) = load_word_list(file) else { continue };
+
+        c.bench_function(&format!("index_word_list/{}", file), |b| {
+            b.iter(|| WordIndex::new(black_box(&lines).iter().map(String::as_str)))
+        });
+    }
+}
+
+criterion_group!(benches, new_game, is_valid_word, index_word_list);
+criterion_main!(benches);
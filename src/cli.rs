@@ -0,0 +1,200 @@
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+use std::time::Duration;
+use thiserror::Error;
+use tui_wordle::dictionary::{get_dictionaries, Difficulty};
+use tui_wordle::game::{GameError, GameOptions, BOARD_COUNTS, MAX_GUESSES, MIN_GUESSES};
+
+/// Error type for command-line argument validation
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("Unknown dictionary '{name}' with {length} letter words, use --list-dictionaries to see the options")]
+    UnknownDictionary { name: String, length: u8 },
+
+    #[error("Guesses must be between {MIN_GUESSES} and {MAX_GUESSES}, got {0}")]
+    InvalidGuesses(u16),
+
+    #[error("Boards must be one of {BOARD_COUNTS:?}, got {0}")]
+    InvalidBoards(u8),
+
+    #[error("Absurdle can only be played on one board, got {0}")]
+    AbsurdleBoards(u8),
+
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError),
+}
+
+/// How common the answers are, as accepted on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CliDifficulty {
+    /// Only the most common words
+    Easy,
+    /// The more common half of the words
+    Medium,
+    /// Any word
+    Hard,
+}
+
+impl From<CliDifficulty> for Difficulty {
+    fn from(difficulty: CliDifficulty) -> Self {
+        match difficulty {
+            CliDifficulty::Easy => Difficulty::Easy,
+            CliDifficulty::Medium => Difficulty::Medium,
+            CliDifficulty::Hard => Difficulty::Hard,
+        }
+    }
+}
+
+/// Command-line arguments for the Wordle TUI application
+#[derive(Debug, Parser)]
+#[command(version, about = "A terminal window implementation of Wordle")]
+pub struct Cli {
+    /// Name of the dictionary to play with
+    #[arg(short, long)]
+    pub dictionary: Option<String>,
+
+    /// Length of the words to guess
+    #[arg(short, long)]
+    pub length: Option<u8>,
+
+    /// Maximum number of guesses allowed
+    #[arg(short, long)]
+    pub guesses: Option<u16>,
+
+    /// Seed for choosing answers, so a session can be replayed
+    #[arg(short, long)]
+    pub seed: Option<u64>,
+
+    /// Require revealed hints to be used in later guesses
+    #[arg(long)]
+    pub hard: bool,
+
+    /// Play the daily word instead of a random one
+    #[arg(long)]
+    pub daily: bool,
+
+    /// Choose the answer adversarially as the game is played, as in Absurdle
+    #[arg(long)]
+    pub absurdle: bool,
+
+    /// Seconds allowed for each game, which is lost when they run out
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub time_limit: Option<u64>,
+
+    /// Seconds allowed for each guess, the game is lost when they run out
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub guess_time_limit: Option<u64>,
+
+    /// Solve this many words back to back as fast as possible
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub speed_run: Option<u16>,
+
+    /// How common the answers are, for dictionaries whose word lists give frequencies
+    #[arg(long, value_enum)]
+    pub difficulty: Option<CliDifficulty>,
+
+    /// Number of boards to play at once: 1, 2 (Dordle), 4 (Quordle) or 8 (Octordle)
+    #[arg(short, long)]
+    pub boards: Option<u8>,
+
+    /// Directory searched before the data directories for dictionary files
+    #[arg(long, value_name = "PATH")]
+    pub dictionary_dir: Option<PathBuf>,
+
+    /// Print every available dictionary and word length, then exit
+    #[arg(long)]
+    pub list_dictionaries: bool,
+}
+
+impl Cli {
+    /// Applies the command-line arguments on top of the given game options
+    ///
+    /// The dictionary name is matched ignoring case. If only one of the dictionary
+    /// name and length is given, the other is taken from `game_options`.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every argument was valid
+    /// * `Err(CliError)` describing the first invalid argument
+    pub fn apply(&self, game_options: &mut GameOptions) -> Result<(), CliError> {
+        if self.dictionary.is_some() || self.length.is_some() {
+            let name = self
+                .dictionary
+                .clone()
+                .unwrap_or_else(|| game_options.dictionary.name.clone());
+            let length = self.length.unwrap_or(game_options.dictionary.length);
+
+            let dictionary = get_dictionaries()
+                .into_iter()
+                .find(|x| x.name.eq_ignore_ascii_case(&name) && x.length == length)
+                .ok_or(CliError::UnknownDictionary { name, length })?;
+
+            game_options.set_dictionary(&dictionary.name, dictionary.length)?;
+        }
+
+        if let Some(guesses) = self.guesses {
+            if !(MIN_GUESSES..=MAX_GUESSES).contains(&guesses) {
+                return Err(CliError::InvalidGuesses(guesses));
+            }
+
+            game_options.max_guesses = guesses;
+        }
+
+        if let Some(boards) = self.boards {
+            if !BOARD_COUNTS.contains(&boards) {
+                return Err(CliError::InvalidBoards(boards));
+            }
+
+            game_options.boards = boards;
+        }
+
+        game_options.seed = self.seed;
+        game_options.hard_mode |= self.hard;
+        game_options.daily |= self.daily;
+        game_options.absurdle |= self.absurdle;
+
+        if let Some(secs) = self.time_limit {
+            game_options.time_limit = Some(Duration::from_secs(secs));
+        }
+
+        if let Some(secs) = self.guess_time_limit {
+            game_options.guess_time_limit = Some(Duration::from_secs(secs));
+        }
+
+        if let Some(difficulty) = self.difficulty {
+            game_options.difficulty = difficulty.into();
+        }
+
+        if self.speed_run.is_some() {
+            game_options.speed_run = self.speed_run;
+        }
+
+        if game_options.absurdle && game_options.boards > 1 {
+            return Err(CliError::AbsurdleBoards(game_options.boards));
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints every available dictionary name and word length
+///
+/// The locale is shown for dictionaries that declare one. Dictionaries whose word list
+/// gives word frequencies are marked as supporting the difficulty, and those whose word
+/// list cannot be found are marked as missing.
+pub fn list_dictionaries() {
+    for dictionary in get_dictionaries() {
+        let mut line = format!("{} {}", dictionary.name, dictionary.length);
+
+        if let Some(locale) = &dictionary.locale {
+            line.push_str(&format!(" [{}]", locale));
+        }
+
+        match dictionary.has_frequencies() {
+            Ok(true) => line.push_str(" (difficulty)"),
+            Ok(false) => {}
+            Err(_) => line.push_str(" (missing)"),
+        }
+
+        println!("{}", line);
+    }
+}
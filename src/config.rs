@@ -0,0 +1,148 @@
+use crate::dictionary::Difficulty;
+use crate::game::{GameOptions, BOARD_COUNTS, MAX_GUESSES, MIN_GUESSES};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Errors that can occur when loading or saving the config file
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// Failed to read or write the config file
+    #[error("Failed to access config file: {0}")]
+    FileError(#[from] std::io::Error),
+
+    /// The config file could not be parsed
+    #[error("Failed to parse config file: {0}")]
+    ParseError(#[from] toml::de::Error),
+
+    /// The config could not be serialized
+    #[error("Failed to serialize config: {0}")]
+    SerializeError(#[from] toml::ser::Error),
+
+    /// The saved dictionary is no longer available
+    #[error("Saved dictionary '{name}' with {length} letter words no longer exists, using {fallback} instead")]
+    DictionaryNotFound { name: String, length: u8, fallback: String },
+}
+
+/// Game options saved between sessions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Config {
+    /// Name of the selected dictionary
+    pub dictionary: String,
+    /// Length of words in the selected dictionary
+    pub length: u8,
+    /// Maximum number of guesses allowed
+    pub max_guesses: u16,
+    /// Whether hard mode is enabled
+    #[serde(default)]
+    pub hard_mode: bool,
+    /// Whether the daily word is played
+    #[serde(default)]
+    pub daily: bool,
+    /// Number of boards played at once
+    #[serde(default = "default_boards")]
+    pub boards: u8,
+    /// Whether the answer is chosen adversarially, as in Absurdle
+    #[serde(default)]
+    pub absurdle: bool,
+    /// Seconds allowed for the whole game, if it is timed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_limit: Option<u64>,
+    /// Seconds allowed for each guess, if guesses are timed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guess_time_limit: Option<u64>,
+    /// Number of words in a speed run, if one is played
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed_run: Option<u16>,
+    /// How common the answers are, for dictionaries that give word frequencies
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+/// Number of boards used by config files saved before multi-board games existed
+fn default_boards() -> u8 {
+    1
+}
+
+impl Config {
+    /// Gets the default location of the config file in the user's config directory
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("tui-wordle").join("config.toml"))
+    }
+
+    /// Creates a config holding the given game options
+    pub fn from_options(game_options: &GameOptions) -> Self {
+        Self {
+            dictionary: game_options.dictionary.name.clone(),
+            length: game_options.dictionary.length,
+            max_guesses: game_options.max_guesses,
+            hard_mode: game_options.hard_mode,
+            daily: game_options.daily,
+            boards: game_options.boards,
+            absurdle: game_options.absurdle,
+            time_limit: game_options.time_limit.map(|limit| limit.as_secs()),
+            guess_time_limit: game_options.guess_time_limit.map(|limit| limit.as_secs()),
+            speed_run: game_options.speed_run,
+            difficulty: game_options.difficulty,
+        }
+    }
+
+    /// Loads the config from the given file
+    ///
+    /// # Returns
+    /// * `Ok(Some(Config))` if the file was loaded
+    /// * `Ok(None)` if the file does not exist
+    /// * `Err(ConfigError)` if the file could not be read or parsed
+    pub fn load(path: &Path) -> Result<Option<Self>, ConfigError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(path)?;
+        Ok(Some(toml::from_str(&contents)?))
+    }
+
+    /// Saves the config to the given file, creating its directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Applies the saved options on top of the given game options
+    ///
+    /// Every other option is still applied if the saved dictionary no longer exists,
+    /// leaving the dictionary in `game_options` unchanged. Absurdle is turned off if
+    /// more than one board is saved, as it can only be played on one.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every option was applied
+    /// * `Err(ConfigError::DictionaryNotFound)` if the saved dictionary was not found
+    pub fn apply(&self, game_options: &mut GameOptions) -> Result<(), ConfigError> {
+        game_options.max_guesses = self.max_guesses.clamp(MIN_GUESSES, MAX_GUESSES);
+        game_options.hard_mode = self.hard_mode;
+        game_options.daily = self.daily;
+        game_options.time_limit = self.time_limit.filter(|secs| *secs > 0).map(Duration::from_secs);
+        game_options.guess_time_limit = self.guess_time_limit.filter(|secs| *secs > 0).map(Duration::from_secs);
+        game_options.speed_run = self.speed_run.filter(|words| *words > 0);
+        game_options.difficulty = self.difficulty;
+        if BOARD_COUNTS.contains(&self.boards) {
+            game_options.boards = self.boards;
+        }
+        // Absurdle can only be played on one board
+        game_options.absurdle = self.absurdle && game_options.boards == 1;
+
+        game_options
+            .set_dictionary(&self.dictionary, self.length)
+            .map_err(|_e| ConfigError::DictionaryNotFound {
+                name: self.dictionary.clone(),
+                length: self.length,
+                fallback: format!("{} {}", game_options.dictionary.name, game_options.dictionary.length),
+            })
+    }
+}
@@ -1,9 +1,16 @@
+use crate::filter::{Constraint, WordFilter};
+use crate::manifest::load_manifests;
+use chrono::NaiveDate;
 use once_cell::sync::Lazy;
-use rand::prelude::IteratorRandom;
+use rand::seq::IndexedRandom;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::fs;
-use std::sync::Arc;
-use std::sync::OnceLock;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 use thiserror::Error;
 
 /// Errors that can occur when working with dictionaries
@@ -16,29 +23,393 @@ pub enum DictionaryError {
     /// No word found matching the criteria
     #[error("No word found matching the criteria")]
     WordNotFound,
+
+    /// A dictionary file was not found on disk and is not built into the binary
+    #[error("Dictionary file '{file}' is not built in and was not found in any of: {}", format_paths(.searched))]
+    FileNotFound { file: String, searched: Vec<PathBuf> },
+}
+
+/// Formats a list of paths for an error message
+fn format_paths(paths: &[PathBuf]) -> String {
+    let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    paths.join(", ")
+}
+
+/// Longest word length a dictionary can be played with
+///
+/// Hints encode the result of each letter of a guess in two bits of a `u64`.
+pub const MAX_WORD_LENGTH: u8 = 31;
+
+/// Directory searched before any other for dictionary files, if one has been set
+static DICTIONARY_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Sets a directory to search before any other for dictionary files
+///
+/// Files found here override both the data directories and the built-in word lists.
+pub fn set_dictionary_dir(dir: Option<PathBuf>) {
+    if let Ok(mut current) = DICTIONARY_DIR.write() {
+        *current = dir;
+    }
+}
+
+/// Gets every directory searched for dictionary files, in order of preference
+///
+/// This is the directory set with `set_dictionary_dir`, then `tui-wordle/dictionaries`
+/// inside the user's data directory and inside each of `$XDG_DATA_DIRS`.
+pub fn dictionary_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(dir) = DICTIONARY_DIR.read().ok().and_then(|dir| dir.clone()) {
+        dirs.push(dir);
+    }
+
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| String::from("/usr/local/share:/usr/share"));
+
+    let shared = data_dirs.split(':').filter(|dir| !dir.is_empty()).map(PathBuf::from);
+
+    dirs.extend(
+        dirs::data_dir()
+            .into_iter()
+            .chain(shared)
+            .map(|dir| dir.join("tui-wordle").join("dictionaries")),
+    );
+
+    dirs
+}
+
+/// Gets the contents of a word list built into the binary
+///
+/// The small Wordle lists are always built in, the others only with the
+/// `bundled-dictionaries` feature.
+fn embedded_word_list(file: &str) -> Option<&'static str> {
+    match file {
+        "wordle.txt" => Some(include_str!("../data/wordle.txt")),
+        "syscalls.txt" => Some(include_str!("../data/syscalls.txt")),
+        #[cfg(feature = "bundled-dictionaries")]
+        "scrabble.txt" => Some(include_str!("../data/scrabble.txt")),
+        #[cfg(feature = "bundled-dictionaries")]
+        "dutch.txt" => Some(include_str!("../data/dutch.txt")),
+        #[cfg(feature = "bundled-dictionaries")]
+        "french.txt" => Some(include_str!("../data/french.txt")),
+        #[cfg(feature = "bundled-dictionaries")]
+        "italian.txt" => Some(include_str!("../data/italian.txt")),
+        _ => None,
+    }
+}
+
+/// Reads the lines of a word list
+///
+/// A file in one of the `dictionary_dirs` takes precedence over the built-in list of
+/// the same name.
+///
+/// # Arguments
+/// * `file` - The file name of the word list, such as `wordle.txt`
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - Every line of the word list
+/// * `Err(DictionaryError::FileNotFound)` - If the file is not on disk or built in
+pub fn load_word_list(file: &str) -> Result<Vec<String>, DictionaryError> {
+    let searched: Vec<PathBuf> = dictionary_dirs().into_iter().map(|dir| dir.join(file)).collect();
+
+    if let Some(path) = searched.iter().find(|path| path.is_file()) {
+        let contents = fs::read_to_string(path)?;
+        return Ok(contents.lines().map(String::from).collect());
+    }
+
+    embedded_word_list(file)
+        .map(|contents| contents.lines().map(String::from).collect())
+        .ok_or_else(|| DictionaryError::FileNotFound {
+            file: file.to_string(),
+            searched,
+        })
+}
+
+/// Keyboard layout used to display the on-screen keyboard for a dictionary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    /// Standard English layout
+    Qwerty,
+    /// French layout
+    Azerty,
+    /// German layout
+    Qwertz,
+}
+
+impl KeyboardLayout {
+    /// Gets the rows of keys for this layout, from top to bottom
+    pub fn rows(&self) -> &'static [&'static str] {
+        match self {
+            KeyboardLayout::Qwerty => &["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"],
+            KeyboardLayout::Azerty => &["AZERTYUIOP", "QSDFGHJKLM", "WXCVBN"],
+            KeyboardLayout::Qwertz => &["QWERTZUIOPÜ", "ASDFGHJKLÖÄ", "YXCVBNM"],
+        }
+    }
+}
+
+/// How accented letters are treated when guessing words from a dictionary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccentPolicy {
+    /// Accents are removed from typed letters and from words before they are compared,
+    /// so `E` matches `É`. The answer is still shown with its accents at the end.
+    Strip,
+    /// Accents must be typed exactly, so `E` and `É` are different letters
+    Exact,
+}
+
+impl AccentPolicy {
+    /// Converts a letter to the upper case form used for matching under this policy
+    ///
+    /// Letters whose upper case is more than one letter, such as `ß` to `SS`, are kept
+    /// as they are so words keep their length.
+    pub fn normalise_letter(&self, letter: char) -> char {
+        let mut upper = letter.to_uppercase();
+        let upper = match (upper.next(), upper.next()) {
+            (Some(upper), None) => upper,
+            _ => letter,
+        };
+
+        match self {
+            AccentPolicy::Strip => strip_accent(upper),
+            AccentPolicy::Exact => upper,
+        }
+    }
+
+    /// Converts a word to the upper case form used for matching under this policy
+    pub fn normalise(&self, word: &str) -> String {
+        word.chars().map(|c| self.normalise_letter(c)).collect()
+    }
+}
+
+/// Removes the accent from a letter, such as `é` to `e` or `Ç` to `C`
+///
+/// Letters without a plain equivalent, such as `æ`, are returned unchanged.
+pub fn strip_accent(letter: char) -> char {
+    match letter {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+        'ç' => 'c',
+        'Ç' => 'C',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'ñ' => 'n',
+        'Ñ' => 'N',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' => 'O',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        'ý' | 'ÿ' => 'y',
+        'Ý' | 'Ÿ' => 'Y',
+        _ => letter,
+    }
+}
+
+/// How common the words chosen as answers are, for dictionaries whose word lists
+/// give a frequency for each word
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    /// Answers are the most common quarter of the words
+    Easy,
+    /// Answers are the most common half of the words
+    #[default]
+    Medium,
+    /// Answers can be any word
+    Hard,
+}
+
+impl Difficulty {
+    /// Gets the share of the words, most common first, that answers are chosen from
+    pub fn answer_share(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.25,
+            Difficulty::Medium => 0.5,
+            Difficulty::Hard => 1.0,
+        }
+    }
+
+    /// Gets the next difficulty, wrapping back to the easiest after the hardest
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Medium,
+            Difficulty::Medium => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "Easy"),
+            Difficulty::Medium => write!(f, "Medium"),
+            Difficulty::Hard => write!(f, "Hard"),
+        }
+    }
+}
+
+/// Words of a word list, grouped by their length in letters
+///
+/// Each line of a word list holds a word, optionally followed by whitespace and how
+/// often the word is used, such as `crane 12.5`. Frequencies can be in any unit as
+/// long as a list uses the same one throughout, since only their order matters.
+///
+/// Words are lower case and appear once each, in the order they first appear in the
+/// file. Entries containing anything but letters, such as spaces or apostrophes,
+/// cannot be typed and are left out. Each file is indexed once by `word_index` and
+/// shared by every dictionary that reads it.
+#[derive(Debug, Default)]
+pub struct WordIndex {
+    /// Words of each length
+    by_length: HashMap<usize, Vec<String>>,
+    /// Frequency of each word that was given one
+    frequencies: HashMap<String, f64>,
+}
+
+impl WordIndex {
+    /// Builds an index from the lines of a word list
+    pub fn new<'a>(lines: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut index = Self::default();
+        let mut seen = HashSet::new();
+
+        for line in lines {
+            let (word, frequency) = split_frequency(line);
+            let word = word.to_lowercase();
+
+            if word.is_empty() || !word.chars().all(char::is_alphabetic) || !seen.insert(word.clone()) {
+                continue;
+            }
+
+            if let Some(frequency) = frequency {
+                index.frequencies.insert(word.clone(), frequency);
+            }
+
+            index.by_length.entry(word.chars().count()).or_default().push(word);
+        }
+
+        index
+    }
+
+    /// Gets every word with the given number of letters
+    pub fn words(&self, length: u8) -> &[String] {
+        self.by_length.get(&(length as usize)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Gets how often a word is used, if the word list gives its frequency
+    pub fn frequency(&self, word: &str) -> Option<f64> {
+        self.frequencies.get(word).copied()
+    }
+}
+
+/// Splits a line of a word list into the word and its frequency, if it has one
+///
+/// A line only has a frequency if it is exactly two fields and the second is a
+/// non-negative number. Anything else is treated as a word, so phrases are still
+/// recognised and skipped.
+fn split_frequency(line: &str) -> (&str, Option<f64>) {
+    let mut fields = line.split_whitespace();
+
+    if let (Some(word), Some(frequency), None) = (fields.next(), fields.next(), fields.next()) {
+        if let Ok(frequency) = frequency.parse::<f64>() {
+            if frequency.is_finite() && frequency >= 0.0 {
+                return (word, Some(frequency));
+            }
+        }
+    }
+
+    (line.trim(), None)
+}
+
+/// Indexes of the word lists read so far, keyed by the path they were read from
+static WORD_INDEXES: Lazy<Mutex<HashMap<PathBuf, Arc<WordIndex>>>> = Lazy::new(Default::default);
+
+/// Gets the index of a word list, reading and indexing it the first time it is needed
+///
+/// The file is looked up in the same way as `load_word_list`. Indexes are cached by
+/// the path the file was found at, so setting a new dictionary directory with
+/// `set_dictionary_dir` is still honoured.
+///
+/// # Arguments
+/// * `file` - The file name of the word list, such as `wordle.txt`
+///
+/// # Returns
+/// * `Ok(Arc<WordIndex>)` - The shared index of the word list
+/// * `Err(DictionaryError)` - If the file could not be found or read
+pub fn word_index(file: &str) -> Result<Arc<WordIndex>, DictionaryError> {
+    let searched: Vec<PathBuf> = dictionary_dirs().into_iter().map(|dir| dir.join(file)).collect();
+
+    let found = searched.iter().find(|path| path.is_file()).cloned();
+
+    // Built-in lists are keyed by their bare file name, which no searched path can be
+    let key = found.clone().unwrap_or_else(|| PathBuf::from(file));
+
+    if let Some(index) = WORD_INDEXES.lock().ok().and_then(|indexes| indexes.get(&key).cloned()) {
+        return Ok(index);
+    }
+
+    // Parse outside the lock so other lists can be read at the same time
+    let index = Arc::new(match found {
+        Some(path) => WordIndex::new(fs::read_to_string(path)?.lines()),
+        None => WordIndex::new(
+            embedded_word_list(file)
+                .ok_or_else(|| DictionaryError::FileNotFound {
+                    file: file.to_string(),
+                    searched,
+                })?
+                .lines(),
+        ),
+    });
+
+    if let Ok(mut indexes) = WORD_INDEXES.lock() {
+        return Ok(Arc::clone(indexes.entry(key).or_insert(index)));
+    }
+
+    Ok(index)
+}
+
+/// Words of a dictionary, filtered and normalised for its length, alphabet and accents
+#[derive(Debug)]
+struct WordSet {
+    /// Possible answers as written in the word list
+    answers: Vec<String>,
+    /// Possible answers normalised for matching, in the same order as `answers`
+    normalised: Vec<String>,
+    /// Positions in `answers`, most common word first, if any answer has a frequency
+    by_frequency: Option<Vec<usize>>,
+    /// Acceptable guesses normalised for matching, starting with the answers
+    guesses: Vec<String>,
+    /// The same words as `guesses`, for quick lookups
+    guess_set: HashSet<String>,
 }
 
 /// Dictionary containing words of a specific length
+#[derive(Clone)]
 pub struct Dictionary {
     /// Name of the dictionary
     pub name: String,
     /// Length of words in this dictionary
     pub length: u8,
-    /// Path to the dictionary file
+    /// Keyboard layout matching the language of the dictionary
+    pub keyboard: KeyboardLayout,
+    /// How accented letters are treated when guessing
+    pub accents: AccentPolicy,
+    /// Letters words may contain, normalised for matching, or None to allow any letter
+    pub alphabet: Option<Vec<char>>,
+    /// Language of the words, such as `de` or `en-GB`, if known
+    pub locale: Option<String>,
+    /// File name of the word list, looked up with `word_index`
     filename: String,
-    /// Lazily loaded function to read words from file
-    all_words: Lazy<Box<dyn Fn(&str) -> Result<Vec<String>, DictionaryError>>>,
-}
-
-impl Clone for Dictionary {
-    fn clone(&self) -> Self {
-        Dictionary {
-            name: self.name.clone(),
-            length: self.length,
-            filename: self.filename.clone(),
-            all_words: Lazy::new(|| Self::load_dictionary()),
-        }
-    }
+    /// Optional file name of a list of common words that answers are chosen from instead
+    /// of the whole word list
+    answers: Option<String>,
+    /// Optional file name of a list of additional words accepted as guesses
+    allowed_guesses: Option<String>,
+    /// Words of this dictionary, built from the shared word list indexes when first needed
+    word_set: OnceLock<Arc<WordSet>>,
 }
 
 impl Debug for Dictionary {
@@ -46,7 +417,13 @@ impl Debug for Dictionary {
         f.debug_struct("Dictionary")
             .field("name", &self.name)
             .field("length", &self.length)
+            .field("keyboard", &self.keyboard)
+            .field("accents", &self.accents)
+            .field("alphabet", &self.alphabet)
+            .field("locale", &self.locale)
             .field("filename", &self.filename)
+            .field("answers", &self.answers)
+            .field("allowed_guesses", &self.allowed_guesses)
             .finish()
     }
 }
@@ -58,77 +435,329 @@ impl Display for Dictionary {
 }
 
 impl Dictionary {
-    /// Creates a function that loads words from a dictionary file
-    fn load_dictionary() -> Box<dyn Fn(&str) -> Result<Vec<String>, DictionaryError>> {
-        Box::new(|filename| {
-            fs::read_to_string(filename)
-                .map(|content| content.lines().map(String::from).collect())
-                .map_err(DictionaryError::from)
-        })
-    }
-
     /// Creates a new dictionary
-    fn new(name: &str, file: &str, word_length: u8) -> Self {
+    pub(crate) fn new(name: &str, file: &str, word_length: u8) -> Self {
         Dictionary {
             name: name.to_string(),
             filename: file.to_string(),
             length: word_length,
-            all_words: Lazy::new(|| Self::load_dictionary()),
+            keyboard: KeyboardLayout::Qwerty,
+            accents: AccentPolicy::Exact,
+            alphabet: None,
+            locale: None,
+            answers: None,
+            allowed_guesses: None,
+            word_set: OnceLock::new(),
         }
     }
 
+    /// Creates a new dictionary that also accepts the words in `guesses_file` as guesses
+    fn with_allowed_guesses(name: &str, file: &str, word_length: u8, guesses_file: &str) -> Self {
+        Self::new(name, file, word_length).with_guesses_file(guesses_file)
+    }
+
+    /// Chooses answers from the words in `answers_file` only
+    ///
+    /// The rest of the word list is still accepted as guesses, so answers can be kept
+    /// to common words without rejecting obscure ones.
+    pub(crate) fn with_answers_file(mut self, answers_file: &str) -> Self {
+        self.answers = Some(answers_file.to_string());
+        self
+    }
+
+    /// Also accepts the words in `guesses_file` as guesses
+    pub(crate) fn with_guesses_file(mut self, guesses_file: &str) -> Self {
+        self.allowed_guesses = Some(guesses_file.to_string());
+        self
+    }
+
+    /// Only allows words made of the letters in `alphabet`
+    pub(crate) fn with_alphabet(mut self, alphabet: &str) -> Self {
+        self.alphabet = Some(self.normalise(alphabet).chars().collect());
+        self
+    }
+
+    /// Sets the language of the words
+    pub(crate) fn with_locale(mut self, locale: &str) -> Self {
+        self.locale = Some(locale.to_string());
+        self
+    }
+
+    /// Sets the keyboard layout used to display this dictionary
+    pub(crate) fn with_keyboard(mut self, keyboard: KeyboardLayout) -> Self {
+        self.keyboard = keyboard;
+        self
+    }
+
+    /// Sets how accented letters are treated when guessing words from this dictionary
+    pub(crate) fn with_accents(mut self, accents: AccentPolicy) -> Self {
+        self.accents = accents;
+        self
+    }
+
+    /// Checks whether a normalised word only uses letters of this dictionary's alphabet
+    fn fits(&self, normalised: &str) -> bool {
+        normalised.chars().all(|c| self.allows_letter(c))
+    }
+
+    /// Gets the words of this dictionary, building them from the word list indexes
+    /// the first time they are needed
+    ///
+    /// Answers come from the answer list if one has been configured, otherwise from the
+    /// word list. Guesses are the answers followed by the rest of the word list and the
+    /// allowed-guesses list. Words that normalise to the same form, such as `côte` and
+    /// `cote` when accents are stripped, are only kept once.
+    fn word_set(&self) -> Result<&WordSet, DictionaryError> {
+        if let Some(words) = self.word_set.get() {
+            return Ok(words);
+        }
+
+        let mut answers = Vec::new();
+        let mut normalised = Vec::new();
+        let mut frequencies = Vec::new();
+        let mut guess_set = HashSet::new();
+
+        let answer_index = word_index(self.answers.as_ref().unwrap_or(&self.filename))?;
+        let word_list_index = word_index(&self.filename)?;
+
+        for word in answer_index.words(self.length) {
+            let normal = self.normalise(word);
+
+            if self.fits(&normal) && guess_set.insert(normal.clone()) {
+                // An answer list without frequencies can use those of the word list
+                frequencies.push(answer_index.frequency(word).or_else(|| word_list_index.frequency(word)));
+                answers.push(word.clone());
+                normalised.push(normal);
+            }
+        }
+
+        // Words without a frequency are treated as the rarest, keeping their file order
+        let by_frequency = frequencies.iter().any(Option::is_some).then(|| {
+            let mut order: Vec<usize> = (0..answers.len()).collect();
+            order.sort_by(|a, b| {
+                let frequency = |i: &usize| frequencies[*i].unwrap_or(0.0);
+                frequency(b).total_cmp(&frequency(a))
+            });
+            order
+        });
+
+        let mut guesses = normalised.clone();
+
+        let extra_files = self
+            .answers
+            .as_ref()
+            .map(|_| &self.filename)
+            .into_iter()
+            .chain(&self.allowed_guesses);
+
+        for file in extra_files {
+            for word in word_index(file)?.words(self.length) {
+                let normal = self.normalise(word);
+
+                if self.fits(&normal) && guess_set.insert(normal.clone()) {
+                    guesses.push(normal);
+                }
+            }
+        }
+
+        let words = Arc::new(WordSet { answers, normalised, by_frequency, guesses, guess_set });
+        Ok(self.word_set.get_or_init(|| words))
+    }
+
+    /// Checks whether a normalised letter is in this dictionary's alphabet
+    pub fn allows_letter(&self, letter: char) -> bool {
+        self.alphabet
+            .as_ref()
+            .is_none_or(|alphabet| alphabet.contains(&letter))
+    }
+
+    /// Converts a word to the upper case form used for matching, following the accent policy
+    pub fn normalise(&self, word: &str) -> String {
+        self.accents.normalise(word)
+    }
+
     /// Gets a random word from the dictionary
-    pub fn random_word(&self) -> Result<String, DictionaryError> {
-        let func = &self.all_words;
-        let contents = func(self.filename.as_str())?;
+    ///
+    /// If the word list gives word frequencies, the word is chosen from the most common
+    /// words allowed by `difficulty`, otherwise from every possible answer.
+    pub fn random_word(&self, difficulty: Difficulty) -> Result<String, DictionaryError> {
+        self.random_word_with(difficulty, &mut rand::rng())
+    }
+
+    /// Gets a random word from the dictionary using the given random number generator
+    pub fn random_word_with<R: Rng + ?Sized>(&self, difficulty: Difficulty, rng: &mut R) -> Result<String, DictionaryError> {
+        let words = self.word_set()?;
 
-        contents
+        let word = match &words.by_frequency {
+            Some(order) => {
+                let count = (order.len() as f64 * difficulty.answer_share()).ceil() as usize;
+                order[..count.min(order.len())].choose(rng).map(|i| &words.answers[*i])
+            }
+            None => words.answers.choose(rng),
+        };
+
+        word.cloned().ok_or(DictionaryError::WordNotFound)
+    }
+
+    /// Gets the daily word for the given date
+    ///
+    /// The word is derived only from the date, the difficulty and the dictionary name
+    /// and length, so every player running the same build with the same difficulty
+    /// gets the same word on the same day.
+    pub fn daily_word(&self, date: NaiveDate, difficulty: Difficulty) -> Result<String, DictionaryError> {
+        let key = format!("{}:{}:{}", self.name, self.length, date.format("%Y-%m-%d"));
+        let mut rng = StdRng::seed_from_u64(fnv1a_hash(key.as_bytes()));
+
+        self.random_word_with(difficulty, &mut rng)
+    }
+
+    /// Checks the word list can be loaded and has at least one possible answer
+    ///
+    /// # Returns
+    /// * `Ok(())` - If a game can be started with this dictionary
+    /// * `Err(DictionaryError::WordNotFound)` - If no word of the dictionary's length fits
+    pub fn check_answers(&self) -> Result<(), DictionaryError> {
+        if self.word_set()?.answers.is_empty() {
+            return Err(DictionaryError::WordNotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the word list gives word frequencies, which the difficulty needs
+    /// to choose more or less common answers
+    pub fn has_frequencies(&self) -> Result<bool, DictionaryError> {
+        Ok(self.word_set()?.by_frequency.is_some())
+    }
+
+    /// Gets every word of the dictionary's length that can be an answer, normalised
+    /// for matching
+    pub fn words(&self) -> Result<Vec<String>, DictionaryError> {
+        Ok(self.word_set()?.normalised.clone())
+    }
+
+    /// Gets every word of the dictionary's length that is an acceptable guess, normalised
+    /// for matching
+    ///
+    /// This is every possible answer followed by the rest of the word list, if answers
+    /// come from a separate answer list, and the allowed-guesses list, if one has been
+    /// configured.
+    pub fn guess_words(&self) -> Result<Vec<String>, DictionaryError> {
+        Ok(self.word_set()?.guesses.clone())
+    }
+
+    /// Gets every possible answer consistent with the results of previous guesses
+    ///
+    /// # Arguments
+    /// * `constraints` - Each previous guess, in upper case, with the result it was given
+    pub fn filter_words(&self, constraints: &[Constraint]) -> Result<Vec<String>, DictionaryError> {
+        let filter = WordFilter::new(constraints);
+
+        Ok(self
+            .word_set()?
+            .normalised
             .iter()
-            .filter(|x| x.len() == self.length as usize)
-            .choose(&mut rand::rng())
+            .filter(|word| filter.matches(&word.chars().collect::<Vec<char>>()))
             .cloned()
-            .ok_or(DictionaryError::WordNotFound)
+            .collect())
+    }
+
+    /// Gets a possible answer as it is written in the word list, with its accents
+    ///
+    /// # Arguments
+    /// * `normalised` - The answer normalised for matching, as returned by `words`
+    ///
+    /// # Returns
+    /// * `Ok(Some(String))` - The answer as written in the word list
+    /// * `Ok(None)` - If the word is not a possible answer
+    pub fn written_answer(&self, normalised: &str) -> Result<Option<String>, DictionaryError> {
+        let words = self.word_set()?;
+
+        Ok(words
+            .normalised
+            .iter()
+            .position(|word| word == normalised)
+            .map(|i| words.answers[i].clone()))
+    }
+
+    /// Checks whether a word is an acceptable guess for this dictionary
+    ///
+    /// A word is acceptable if it appears, ignoring case, in the dictionary word list,
+    /// the answer list or the allowed-guesses list, if those have been configured.
+    /// Accents are ignored too if the accent policy strips them.
+    pub fn is_valid_word(&self, word: &str) -> Result<bool, DictionaryError> {
+        Ok(self.word_set()?.guess_set.contains(&self.normalise(word)))
     }
 }
 
+/// Hashes bytes with 64-bit FNV-1a
+///
+/// Used instead of the standard library hasher because its output is not guaranteed
+/// to be stable, and the daily word must be the same for every player.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(PRIME)
+    })
+}
+
 thread_local! {
     // Dictionary cache to avoid reloading dictionaries
-    static DICTIONARY_CACHE: OnceLock<Vec<Arc<Dictionary>>> = OnceLock::new();
+    static DICTIONARY_CACHE: OnceLock<Vec<Arc<Dictionary>>> = const { OnceLock::new() };
 }
 
 /// Gets all available dictionaries
 ///
+/// These are the built-in dictionaries followed by any declared in a manifest in
+/// one of the `dictionary_dirs`, which replace a built-in dictionary with the same
+/// name and length. Manifests that cannot be loaded are skipped; use
+/// `manifest::load_manifests` to find out why.
+///
 /// Returns a vector of Arc pointers to dictionaries.
 /// Since Arc is a reference-counted pointer, cloning it is cheap.
 pub fn get_dictionaries() -> Vec<Arc<Dictionary>> {
     DICTIONARY_CACHE.with(|local| {
         local
             .get_or_init(|| {
-                vec![
-                    Arc::new(Dictionary::new("Wordle", "data/wordle.txt", 5)),
-                    Arc::new(Dictionary::new("Scrabble", "data/scrabble.txt", 4)),
-                    Arc::new(Dictionary::new("Scrabble", "data/scrabble.txt", 5)),
-                    Arc::new(Dictionary::new("Scrabble", "data/scrabble.txt", 6)),
-                    Arc::new(Dictionary::new("Scrabble", "data/scrabble.txt", 7)),
-                    Arc::new(Dictionary::new("Dutch", "data/dutch.txt", 4)),
-                    Arc::new(Dictionary::new("Dutch", "data/dutch.txt", 5)),
-                    Arc::new(Dictionary::new("Dutch", "data/dutch.txt", 6)),
-                    Arc::new(Dictionary::new("Dutch", "data/dutch.txt", 7)),
-                    Arc::new(Dictionary::new("Dutch", "data/dutch.txt", 8)),
-                    Arc::new(Dictionary::new("French", "data/french.txt", 4)),
-                    Arc::new(Dictionary::new("French", "data/french.txt", 5)),
-                    Arc::new(Dictionary::new("French", "data/french.txt", 6)),
-                    Arc::new(Dictionary::new("French", "data/french.txt", 7)),
-                    Arc::new(Dictionary::new("French", "data/french.txt", 8)),
-                    Arc::new(Dictionary::new("Italian", "data/italian.txt", 4)),
-                    Arc::new(Dictionary::new("Italian", "data/italian.txt", 5)),
-                    Arc::new(Dictionary::new("Italian", "data/italian.txt", 6)),
-                    Arc::new(Dictionary::new("Italian", "data/italian.txt", 7)),
-                    Arc::new(Dictionary::new("Italian", "data/italian.txt", 8)),
-                ]
+                let mut dictionaries = built_in_dictionaries();
+
+                for dictionary in load_manifests().0 {
+                    dictionaries.retain(|d| d.name != dictionary.name || d.length != dictionary.length);
+                    dictionaries.push(Arc::new(dictionary));
+                }
+
+                dictionaries
             })
             .iter()
             .map(Arc::clone)
             .collect()
     })
 }
+
+/// Gets the dictionaries that are always available
+fn built_in_dictionaries() -> Vec<Arc<Dictionary>> {
+    vec![
+        Arc::new(Dictionary::with_allowed_guesses("Wordle", "wordle.txt", 5, "syscalls.txt")),
+        Arc::new(Dictionary::new("Scrabble", "scrabble.txt", 4)),
+        Arc::new(Dictionary::new("Scrabble", "scrabble.txt", 5).with_answers_file("wordle.txt")),
+        Arc::new(Dictionary::new("Scrabble", "scrabble.txt", 6)),
+        Arc::new(Dictionary::new("Scrabble", "scrabble.txt", 7)),
+        Arc::new(Dictionary::new("Dutch", "dutch.txt", 4).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Dutch", "dutch.txt", 5).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Dutch", "dutch.txt", 6).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Dutch", "dutch.txt", 7).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Dutch", "dutch.txt", 8).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("French", "french.txt", 4).with_keyboard(KeyboardLayout::Azerty).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("French", "french.txt", 5).with_keyboard(KeyboardLayout::Azerty).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("French", "french.txt", 6).with_keyboard(KeyboardLayout::Azerty).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("French", "french.txt", 7).with_keyboard(KeyboardLayout::Azerty).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("French", "french.txt", 8).with_keyboard(KeyboardLayout::Azerty).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Italian", "italian.txt", 4).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Italian", "italian.txt", 5).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Italian", "italian.txt", 6).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Italian", "italian.txt", 7).with_accents(AccentPolicy::Strip)),
+        Arc::new(Dictionary::new("Italian", "italian.txt", 8).with_accents(AccentPolicy::Strip)),
+    ]
+}
@@ -0,0 +1,84 @@
+use crate::game::LetterResult;
+use std::collections::HashMap;
+
+/// A submitted guess together with the result it was given
+pub type Constraint = (Vec<char>, Vec<LetterResult>);
+
+/// Letter requirements that a possible answer must meet, built from guess results
+///
+/// Checking a word against the requirements does not need to score every guess
+/// against it, so filtering large word lists is cheap.
+#[derive(Debug, Clone, Default)]
+pub struct WordFilter {
+    /// Letters known to be in each position
+    fixed: HashMap<usize, char>,
+    /// Letters known not to be in each position
+    excluded: HashMap<usize, Vec<char>>,
+    /// Fewest copies of each letter the answer must contain
+    min_counts: HashMap<char, usize>,
+    /// Most copies of each letter the answer can contain
+    max_counts: HashMap<char, usize>,
+}
+
+impl WordFilter {
+    /// Creates a filter matching every word consistent with the given guess results
+    pub fn new(constraints: &[Constraint]) -> Self {
+        let mut filter = Self::default();
+
+        for (guess, result) in constraints {
+            // Copies of each letter this guess revealed to be in the answer
+            let mut revealed: HashMap<char, usize> = HashMap::new();
+            for (&letter, result) in guess.iter().zip(result) {
+                if matches!(result, LetterResult::Correct | LetterResult::Present) {
+                    *revealed.entry(letter).or_insert(0) += 1;
+                }
+            }
+
+            for (i, (&letter, result)) in guess.iter().zip(result).enumerate() {
+                match result {
+                    LetterResult::Correct => {
+                        filter.fixed.insert(i, letter);
+                    }
+                    LetterResult::Present => {
+                        filter.excluded.entry(i).or_default().push(letter);
+                    }
+                    LetterResult::Absent => {
+                        // An absent copy means the answer has no more copies than were revealed
+                        filter.excluded.entry(i).or_default().push(letter);
+                        let max = revealed.get(&letter).copied().unwrap_or(0);
+                        let current = filter.max_counts.entry(letter).or_insert(max);
+                        *current = (*current).min(max);
+                    }
+                    LetterResult::Empty => {}
+                }
+            }
+
+            for (letter, count) in revealed {
+                let current = filter.min_counts.entry(letter).or_insert(count);
+                *current = (*current).max(count);
+            }
+        }
+
+        filter
+    }
+
+    /// Checks whether a word meets every requirement
+    pub fn matches(&self, word: &[char]) -> bool {
+        for (&i, &letter) in &self.fixed {
+            if word.get(i) != Some(&letter) {
+                return false;
+            }
+        }
+
+        for (&i, letters) in &self.excluded {
+            if word.get(i).is_some_and(|c| letters.contains(c)) {
+                return false;
+            }
+        }
+
+        let count = |letter: char| word.iter().filter(|c| **c == letter).count();
+
+        self.min_counts.iter().all(|(&letter, &min)| count(letter) >= min)
+            && self.max_counts.iter().all(|(&letter, &max)| count(letter) <= max)
+    }
+}
@@ -1,7 +1,20 @@
-use crate::dictionary::{get_dictionaries, Dictionary};
+use crate::dictionary::{get_dictionaries, Dictionary, Difficulty};
+use crate::filter::Constraint;
+use chrono::{Local, NaiveDate};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::cmp::PartialEq;
+use std::collections::HashMap;
 use std::fmt::{Debug};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Smallest number of guesses that can be configured
+pub const MIN_GUESSES: u16 = 3;
+/// Largest number of guesses that can be configured
+pub const MAX_GUESSES: u16 = 10;
+/// Numbers of boards that can be played at once
+pub const BOARD_COUNTS: [u8; 4] = [1, 2, 4, 8];
 
 #[derive(Debug, thiserror::Error)]
 pub enum GameError {
@@ -20,14 +33,46 @@ pub enum GameError {
     #[error("Cannot add to a full guess")]
     FullGuess,
 
+    #[error("Nothing to delete before the cursor")]
+    StartOfGuess,
+
     #[error("Guess is incomplete")]
     IncompleteGuess,
 
+    #[error("{0} is not in the alphabet of this dictionary")]
+    LetterNotInAlphabet(char),
+
+    #[error("Not in word list: {0}")]
+    UnknownWord(String),
+
+    #[error("Letter {position} must be {letter}")]
+    HardModeMissingCorrect { letter: char, position: usize },
+
+    #[error("Guess must contain {0}")]
+    HardModeMissingPresent(char),
+
     #[error("Internal error: {0}")]
     InternalError(String),
 }
 
-#[derive(Hash, Eq, PartialEq, Clone, Debug, Copy)]
+impl GameError {
+    /// Checks whether this error means a submitted guess was rejected
+    pub fn is_rejected_guess(&self) -> bool {
+        matches!(
+            self,
+            GameError::IncompleteGuess
+                | GameError::UnknownWord(_)
+                | GameError::HardModeMissingCorrect { .. }
+                | GameError::HardModeMissingPresent(_)
+        )
+    }
+}
+
+/// Result for a single letter of a guess
+///
+/// Variants are ordered from least to most informative, so the best-known state of a
+/// letter across several guesses is the maximum of its results.
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Copy)]
 pub enum LetterResult {
     Empty,
     Absent,
@@ -44,6 +89,24 @@ pub struct GameOptions {
     pub max_guesses: u16,
     /// Dictionary used for the game
     pub dictionary: Arc<Dictionary>,
+    /// Whether revealed hints must be used in later guesses
+    pub hard_mode: bool,
+    /// Whether to play the daily word instead of a random one
+    pub daily: bool,
+    /// Seed used to choose the answer, if answers should be reproducible
+    pub seed: Option<u64>,
+    /// Number of boards played at once, one of `BOARD_COUNTS`
+    pub boards: u8,
+    /// Whether the answer is chosen adversarially as the game is played, as in Absurdle
+    pub absurdle: bool,
+    /// Time allowed for the whole game, which is lost when it runs out
+    pub time_limit: Option<Duration>,
+    /// Time allowed for each guess, the game is lost when it runs out
+    pub guess_time_limit: Option<Duration>,
+    /// Number of words to solve back to back in a speed run, if one is being played
+    pub speed_run: Option<u16>,
+    /// How common the answers are, for dictionaries that give word frequencies
+    pub difficulty: Difficulty,
 }
 
 impl Default for GameOptions {
@@ -61,15 +124,42 @@ impl Default for GameOptions {
             word_length: default_dictionary.length as u16,
             max_guesses: 6,
             dictionary: Arc::clone(default_dictionary),
+            hard_mode: false,
+            daily: false,
+            seed: None,
+            boards: 1,
+            absurdle: false,
+            time_limit: None,
+            guess_time_limit: None,
+            speed_run: None,
+            difficulty: Difficulty::default(),
         }
     }
 }
 
 impl GameOptions {
+    /// Gets whether games are played against the clock
+    pub fn is_timed(&self) -> bool {
+        self.time_limit.is_some() || self.guess_time_limit.is_some() || self.speed_run.is_some()
+    }
+
     /// Gets a random word from the current dictionary
+    ///
+    /// If a seed has been set the same word is returned for the same seed. The word is
+    /// chosen from the answers allowed by the difficulty.
     pub fn random_word(&self) -> Result<String, GameError> {
+        let word = match self.seed {
+            Some(seed) => self.dictionary.random_word_with(self.difficulty, &mut StdRng::seed_from_u64(seed)),
+            None => self.dictionary.random_word(self.difficulty),
+        };
+
+        word.map_err(|_e| GameError::DictionaryError)
+    }
+
+    /// Gets the daily word for the given date from the current dictionary
+    pub fn daily_word(&self, date: NaiveDate) -> Result<String, GameError> {
         self.dictionary
-            .random_word()
+            .daily_word(date, self.difficulty)
             .map_err(|_e| GameError::DictionaryError)
     }
 
@@ -88,7 +178,7 @@ impl GameOptions {
         let dictionary = dictionaries
             .iter()
             .find(|x| x.name == name && x.length == length)
-            .ok_or_else(|| GameError::DictionaryError)?;
+            .ok_or(GameError::DictionaryError)?;
 
         self.dictionary = Arc::clone(dictionary);
         self.word_length = length as u16;
@@ -97,6 +187,49 @@ impl GameOptions {
     }
 }
 
+/// Scores a guess against an answer
+///
+/// Letters in the right position are `Correct`. Each remaining letter is `Present` if
+/// the answer has an unmatched copy of it elsewhere, claiming copies from left to right,
+/// and `Absent` otherwise. So a letter is never marked `Correct` or `Present` more times
+/// than it appears in the answer.
+///
+/// Letters are compared exactly, so callers must normalise case beforehand. The guess
+/// and answer may be any length; guess letters beyond the end of the answer can only be
+/// `Present` or `Absent`.
+///
+/// # Returns
+/// One result for each letter of the guess
+pub fn score_guess(answer: &[char], guess: &[char]) -> Vec<LetterResult> {
+    // Letters of the answer that have not been matched by a letter of the guess yet
+    let mut unmatched: Vec<Option<char>> = answer.iter().copied().map(Some).collect();
+    let mut result = vec![LetterResult::Absent; guess.len()];
+
+    // First pass: check for correct letters
+    for (i, &g) in guess.iter().enumerate() {
+        if unmatched.get(i) == Some(&Some(g)) {
+            result[i] = LetterResult::Correct;
+            unmatched[i] = None;
+        }
+    }
+
+    // Second pass: check for present letters
+    for (i, &g) in guess.iter().enumerate() {
+        // Skip letters that are already marked as correct
+        if result[i] == LetterResult::Correct {
+            continue;
+        }
+
+        // Check if the letter is present elsewhere in the answer
+        if let Some(pos) = unmatched.iter().position(|&a| a == Some(g)) {
+            result[i] = LetterResult::Present;
+            unmatched[pos] = None;
+        }
+    }
+
+    result
+}
+
 #[derive(Clone, Eq, PartialEq, Debug, Copy)]
 enum GuessState {
     Active,
@@ -107,7 +240,10 @@ enum GuessState {
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct Guess {
     max_length: u16,
-    letters: Vec<char>,
+    /// Letter in each cell, None if the cell is empty
+    letters: Vec<Option<char>>,
+    /// Cell the next letter is typed into, equal to `max_length` once past the last cell
+    cursor: u16,
     result: Option<Vec<LetterResult>>,
     state: GuessState,
 }
@@ -116,7 +252,8 @@ impl Guess {
     fn new(max_length: u16) -> Self {
         Self {
             max_length,
-            letters: Vec::new(),
+            letters: vec![None; max_length as usize],
+            cursor: 0,
             result: None,
             state: GuessState::Pending,
         }
@@ -134,52 +271,101 @@ impl Guess {
             .collect()
     }
 
+    /// Types a letter into the cell under the cursor, replacing any letter there, and
+    /// moves the cursor to the next cell
+    ///
+    /// If the cursor is past the last cell the letter goes into the first empty cell
+    /// instead, so a guess is only full once every cell has a letter.
     fn add_letter(&mut self, c: char) -> Result<(), GameError> {
-        if self.remaining_letters() <= 0 {
-            return Err(GameError::FullGuess);
+        if self.cursor >= self.max_length {
+            let first_empty = self.letters.iter().position(Option::is_none).ok_or(GameError::FullGuess)?;
+            self.cursor = first_empty as u16;
         }
 
-        self.letters.push(c);
+        self.letters[self.cursor as usize] = Some(c);
+        self.cursor += 1;
         Ok(())
     }
 
+    /// Clears the cell before the cursor and moves the cursor back to it
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the cell was cleared
+    /// * `Err(GameError::EmptyGuess)` - If the guess has no letters
+    /// * `Err(GameError::StartOfGuess)` - If the cursor is on the first cell
     fn delete_letter(&mut self) -> Result<(), GameError> {
         if self.remaining_letters() == self.max_length {
             return Err(GameError::EmptyGuess);
         }
 
-        self.letters.pop();
+        if self.cursor == 0 {
+            return Err(GameError::StartOfGuess);
+        }
+
+        self.cursor -= 1;
+        self.letters[self.cursor as usize] = None;
         Ok(())
     }
 
+    /// Clears the cell under the cursor, leaving the cursor where it is
+    fn clear_letter(&mut self) {
+        if let Some(letter) = self.letters.get_mut(self.cursor as usize) {
+            *letter = None;
+        }
+    }
+
+    /// Clears every cell and moves the cursor back to the first cell
+    fn clear(&mut self) {
+        self.letters = vec![None; self.max_length as usize];
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor one cell to the left, stopping at the first cell
+    fn move_cursor_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Moves the cursor one cell to the right, stopping past the last cell
+    fn move_cursor_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.max_length);
+    }
+
     fn remaining_letters(&self) -> u16 {
-        self.max_length - (self.letters.len() as u16)
+        self.letters.iter().filter(|c| c.is_none()).count() as u16
     }
 
+    /// Gets the cell under the cursor, if the cursor is on a cell
+    pub fn cursor(&self) -> Option<u16> {
+        (self.cursor < self.max_length).then_some(self.cursor)
+    }
+
+    /// Gets the letters that have been typed, skipping empty cells
     pub fn as_chars(&self) -> Vec<char> {
-        self.letters.clone()
+        self.letters.iter().flatten().copied().collect()
+    }
+
+    /// Gets the result of each letter, if the guess has been submitted
+    pub fn result(&self) -> Option<&[LetterResult]> {
+        self.result.as_deref()
     }
 
-    fn complete_guess(&mut self, result: &Vec<LetterResult>) {
-        self.result = Some(result.clone());
+    fn complete_guess(&mut self, result: &[LetterResult]) {
+        self.result = Some(result.to_vec());
         self.state = GuessState::Complete;
     }
 
     pub fn values(&self) -> Vec<(Option<char>, Option<LetterResult>)> {
         if let Some(results) = &self.result {
-            self.letters
-                .iter()
+            self.as_chars()
+                .into_iter()
                 .zip(results.iter())
-                .map(|(c, result)| (Some(*c), Some(*result)))
+                .map(|(c, result)| (Some(c), Some(*result)))
                 .collect()
         } else {
-            let mut result = vec![(Some(' '), Some(LetterResult::Empty)); self.max_length as usize];
-
-            for (i, c) in self.letters.iter().enumerate() {
-                result[i] = (Some(c.to_ascii_uppercase()), Some(LetterResult::Empty));
-            }
-
-            result
+            self.letters
+                .iter()
+                .map(|c| (Some(c.unwrap_or(' ')), Some(LetterResult::Empty)))
+                .collect()
         }
     }
 }
@@ -195,6 +381,22 @@ pub enum GameState {
 pub struct GameData {
     pub game_state: GameState,
     pub answer: String,
+    /// Whether the answer is still to be chosen, which only happens in Absurdle mode
+    answer_pending: bool,
+    /// Number of hints the player has asked for
+    pub hints_used: u16,
+    /// Number of possible answers remaining after each submitted guess
+    remaining_candidates: Vec<usize>,
+    /// Date of the daily puzzle being played, if this is a daily game
+    pub daily: Option<NaiveDate>,
+    /// When the game started
+    started_at: Instant,
+    /// When the active guess started, for the per-guess time limit
+    guess_started_at: Instant,
+    /// When the game finished, so the clock stops
+    finished_at: Option<Instant>,
+    /// Whether the game was lost by running out of time
+    timed_out: bool,
     game_options: GameOptions,
     pub guesses: Vec<Guess>,
 }
@@ -202,26 +404,170 @@ pub struct GameData {
 impl GameData {
     /// Creates a new game with the given options
     ///
+    /// If daily mode is enabled the answer is today's daily word, otherwise it is
+    /// chosen at random. In Absurdle mode no answer is chosen until the guesses force
+    /// one, so `answer` is empty and daily mode is ignored.
+    ///
     /// # Panics
     /// Panics if a random word cannot be retrieved from the dictionary.
     /// This is a design choice to fail fast if the dictionary is not available,
     /// as the game cannot function without a word to guess.
     pub fn new(opts: &GameOptions) -> Self {
-        let word = opts
-            .random_word()
-            .unwrap_or_else(|e| {
-                eprintln!("Failed to get random word: {}", e);
-                panic!("Cannot start game without a word to guess")
-            });
+        if opts.absurdle {
+            return Self {
+                game_state: GameState::Active,
+                game_options: opts.clone(),
+                answer: String::new(),
+                answer_pending: true,
+                hints_used: 0,
+                remaining_candidates: Vec::new(),
+                daily: None,
+                started_at: Instant::now(),
+                guess_started_at: Instant::now(),
+                finished_at: None,
+                timed_out: false,
+                guesses: Guess::make_vec(opts.word_length, opts.max_guesses),
+            };
+        }
+
+        let daily = opts.daily.then(|| Local::now().date_naive());
+
+        let word = match daily {
+            Some(date) => opts.daily_word(date),
+            None => opts.random_word(),
+        };
+
+        let word = word.unwrap_or_else(|e| {
+            eprintln!("Failed to get random word: {}", e);
+            panic!("Cannot start game without a word to guess")
+        });
 
         Self {
             game_state: GameState::Active,
             game_options: opts.clone(),
             answer: word,
+            answer_pending: false,
+            hints_used: 0,
+            remaining_candidates: Vec::new(),
+            daily,
+            started_at: Instant::now(),
+            guess_started_at: Instant::now(),
+            finished_at: None,
+            timed_out: false,
             guesses: Guess::make_vec(opts.word_length, opts.max_guesses),
         }
     }
 
+    /// Gets the options the game was created with
+    pub fn options(&self) -> &GameOptions {
+        &self.game_options
+    }
+
+    /// Gets whether the answer is still to be chosen, as it is early in an Absurdle game
+    pub fn is_answer_pending(&self) -> bool {
+        self.answer_pending
+    }
+
+    /// Gets whether the game was lost by running out of time
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /// Gets the time taken so far, or the final time if the game has finished
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.finished_at
+            .unwrap_or(now)
+            .saturating_duration_since(self.started_at)
+    }
+
+    /// Gets the time left before the game is lost, if it has a time limit
+    pub fn time_remaining(&self, now: Instant) -> Option<Duration> {
+        self.game_options
+            .time_limit
+            .map(|limit| limit.saturating_sub(self.elapsed(now)))
+    }
+
+    /// Gets the time left for the active guess, if guesses have a time limit
+    ///
+    /// Finished games have no active guess, so no time is returned for them.
+    pub fn guess_time_remaining(&self, now: Instant) -> Option<Duration> {
+        if !self.is_active() {
+            return None;
+        }
+
+        self.game_options
+            .guess_time_limit
+            .map(|limit| limit.saturating_sub(now.saturating_duration_since(self.guess_started_at)))
+    }
+
+    /// Checks the time limits, losing the game if either has run out
+    ///
+    /// # Returns
+    /// * `Ok(GameState)` - The new state of the game
+    /// * `Err(GameError)` - If an Absurdle answer could not be chosen to reveal
+    pub fn check_time(&mut self, now: Instant) -> Result<GameState, GameError> {
+        let out_of_time = self.time_remaining(now) == Some(Duration::ZERO)
+            || self.guess_time_remaining(now) == Some(Duration::ZERO);
+
+        if self.is_active() && out_of_time {
+            self.time_out(now)?;
+        }
+
+        Ok(self.game_state)
+    }
+
+    /// Loses the game by running out of time
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the game was lost
+    /// * `Err(GameError)` - If an Absurdle answer could not be chosen to reveal
+    pub(crate) fn time_out(&mut self, now: Instant) -> Result<(), GameError> {
+        self.game_state = GameState::Lost;
+        self.timed_out = true;
+        self.finished_at = Some(now);
+        self.reveal_pending_answer()
+    }
+
+    /// Gets the number of guesses that have been submitted
+    pub fn guesses_used(&self) -> u16 {
+        self.guesses
+            .iter()
+            .filter(|g| g.state == GuessState::Complete)
+            .count() as u16
+    }
+
+    /// Gets the number of possible answers remaining after each submitted guess
+    pub fn remaining_candidates(&self) -> &[usize] {
+        &self.remaining_candidates
+    }
+
+    /// Gets the letters and results of every submitted guess, in the order they were made
+    pub fn completed_guesses(&self) -> Vec<Constraint> {
+        self.guesses
+            .iter()
+            .filter_map(|g| g.result().map(|result| (g.as_chars(), result.to_vec())))
+            .collect()
+    }
+
+    /// Gets the best-known state of every letter used in a completed guess
+    ///
+    /// A letter that has been `Correct` anywhere is reported as `Correct`, otherwise
+    /// `Present` beats `Absent`. Letters that have not been guessed are not included.
+    pub fn letter_states(&self) -> HashMap<char, LetterResult> {
+        let mut states = HashMap::new();
+
+        for guess in self.guesses.iter().filter(|g| g.state == GuessState::Complete) {
+            for (letter, result) in guess.values() {
+                if let (Some(letter), Some(result)) = (letter, result) {
+                    let state = states.entry(letter).or_insert(result);
+                    *state = (*state).max(result);
+                }
+            }
+        }
+
+        states
+    }
+
     fn active_guess(&mut self) -> Option<(u16, &mut Guess)> {
         let idx = self
             .guesses
@@ -244,8 +590,13 @@ impl GameData {
             return Err(GameError::NoActiveGame);
         }
 
+        let letter = self.game_options.dictionary.accents.normalise_letter(val);
+        if !self.game_options.dictionary.allows_letter(letter) {
+            return Err(GameError::LetterNotInAlphabet(letter));
+        }
+
         let guess = self.active_guess().ok_or(GameError::NoActiveGuess)?;
-        guess.1.add_letter(val.to_ascii_uppercase())
+        guess.1.add_letter(letter)
     }
 
     pub fn delete_letter(&mut self) -> Result<(), GameError> {
@@ -257,92 +608,283 @@ impl GameData {
         guess.1.delete_letter()
     }
 
-    /// Submits the current word and checks if it matches the answer
+    /// Applies an edit to the active guess
+    fn edit_active_guess(&mut self, edit: impl FnOnce(&mut Guess)) -> Result<(), GameError> {
+        if !self.is_active() {
+            return Err(GameError::NoActiveGame);
+        }
+
+        let guess = self.active_guess().ok_or(GameError::NoActiveGuess)?;
+        edit(guess.1);
+        Ok(())
+    }
+
+    /// Moves the cursor of the active guess one cell to the left
+    pub fn move_cursor_left(&mut self) -> Result<(), GameError> {
+        self.edit_active_guess(Guess::move_cursor_left)
+    }
+
+    /// Moves the cursor of the active guess one cell to the right
+    pub fn move_cursor_right(&mut self) -> Result<(), GameError> {
+        self.edit_active_guess(Guess::move_cursor_right)
+    }
+
+    /// Clears the letter under the cursor of the active guess
+    pub fn clear_letter(&mut self) -> Result<(), GameError> {
+        self.edit_active_guess(Guess::clear_letter)
+    }
+
+    /// Clears every letter of the active guess
+    pub fn clear_guess(&mut self) -> Result<(), GameError> {
+        self.edit_active_guess(Guess::clear)
+    }
+
+    /// Gets the position of the cursor in the active guess
     ///
     /// # Returns
-    /// * `Ok(GameState)` - The new state of the game
-    /// * `Err(GameError)` - If there was an error submitting the word
-    pub fn submit_word(&mut self) -> Result<GameState, GameError> {
+    /// The index of the active guess and of the cell under the cursor, or None if the
+    /// game has finished or the cursor is past the last cell
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        if !self.is_active() {
+            return None;
+        }
+
+        self.guesses
+            .iter()
+            .position(|g| g.state == GuessState::Active)
+            .and_then(|idx| Some((idx as u16, self.guesses[idx].cursor()?)))
+    }
+
+    /// Checks whether the active guess would be accepted, without changing the game
+    ///
+    /// # Returns
+    /// * `Ok(())` - If `submit_word` would accept the guess
+    /// * `Err(GameError)` - Why the guess would be rejected
+    pub fn validate_guess(&self) -> Result<(), GameError> {
+        self.guess_to_submit().map(|_| ())
+    }
+
+    /// Finds the active guess and checks that it can be submitted
+    ///
+    /// # Returns
+    /// * `Ok((u16, Vec<char>))` - The index and letters of the active guess
+    /// * `Err(GameError)` - Why the guess would be rejected
+    fn guess_to_submit(&self) -> Result<(u16, Vec<char>), GameError> {
         // Check if the game is active
         if self.game_state != GameState::Active {
             return Err(GameError::NoActiveGame);
         }
 
         // Find the active guess
-        let active_guess = self.guesses
-            .iter_mut()
+        let (guess_idx, guess) = self.guesses
+            .iter()
             .enumerate()
             .find(|(_, g)| g.state == GuessState::Active)
             .ok_or_else(|| GameError::InternalError("No active guess found".to_string()))?;
 
-        let (guess_idx, guess) = active_guess;
-        let guess_idx = guess_idx as u16;
-
         // Check if the guess is complete
         if guess.remaining_letters() > 0 {
             return Err(GameError::IncompleteGuess);
         }
 
-        // Get the guess characters before borrowing self again
         let guess_chars = guess.as_chars();
 
-        // Process the guess
-        let result = Self::check_guess(
-            &self.answer,
-            self.game_options.word_length,
-            &guess_chars
-        );
+        // Check the guess is a real word before accepting it
+        let word: String = guess_chars.iter().collect();
+        let is_valid = self.game_options
+            .dictionary
+            .is_valid_word(&word)
+            .map_err(|_e| GameError::DictionaryError)?;
+
+        if !is_valid {
+            return Err(GameError::UnknownWord(word));
+        }
+
+        if self.game_options.hard_mode {
+            Self::check_hard_mode(&self.guesses[..guess_idx], &guess_chars)?;
+        }
+
+        Ok((guess_idx as u16, guess_chars))
+    }
+
+    /// Submits the current word and checks if it matches the answer
+    ///
+    /// # Returns
+    /// * `Ok(GameState)` - The new state of the game
+    /// * `Err(GameError)` - If there was an error submitting the word
+    pub fn submit_word(&mut self) -> Result<GameState, GameError> {
+        let (guess_idx, guess_chars) = self.guess_to_submit()?;
+
+        // Process the guess, letting Absurdle mode pick the least helpful result
+        let result = if self.answer_pending {
+            self.absurdle_result(&guess_chars)?
+        } else {
+            self.check_guess(&guess_chars)
+        };
+
+        self.guesses[guess_idx as usize].complete_guess(&result);
 
-        guess.complete_guess(&result);
+        // Count the words still consistent with every result so far
+        let remaining = self.game_options
+            .dictionary
+            .filter_words(&self.completed_guesses())
+            .map_err(|_e| GameError::DictionaryError)?;
+        self.remaining_candidates.push(remaining.len());
 
         // Update game state based on the result
         self.update_game_state(guess_idx, &result);
 
+        if self.game_state == GameState::Lost {
+            self.reveal_pending_answer()?;
+        }
+
         Ok(self.game_state)
     }
 
-    /// Checks a guess against the answer and returns the result
-    fn check_guess(answer: &str, word_length: u16, guess_chars: &[char]) -> Vec<LetterResult> {
-        let mut answer_chars: Vec<_> = answer.to_ascii_uppercase().chars().collect();
-        let mut result = vec![LetterResult::Absent; word_length as usize];
-
-        // First pass: check for correct letters
-        for i in 0..guess_chars.len() {
-            if guess_chars[i] == answer_chars[i].to_ascii_uppercase() {
-                result[i] = LetterResult::Correct;
-                // Mark this character as used
-                answer_chars[i] = char::MIN;
-            }
+    /// Chooses an Absurdle answer that fits every result so far, so it can be revealed
+    fn reveal_pending_answer(&mut self) -> Result<(), GameError> {
+        if !self.answer_pending {
+            return Ok(());
+        }
+
+        let remaining = self.game_options
+            .dictionary
+            .filter_words(&self.completed_guesses())
+            .map_err(|_e| GameError::DictionaryError)?;
+
+        if let Some(answer) = remaining.first() {
+            self.commit_answer(answer)?;
         }
 
-        // Second pass: check for present letters
-        for (i, &g) in guess_chars.iter().enumerate() {
-            // Skip letters that are already marked as correct
-            if result[i] == LetterResult::Correct {
+        Ok(())
+    }
+
+    /// Fixes the Absurdle answer, keeping it as written in the word list so its
+    /// accents are shown at the end
+    fn commit_answer(&mut self, normalised: &str) -> Result<(), GameError> {
+        self.answer = self.game_options
+            .dictionary
+            .written_answer(normalised)
+            .map_err(|_e| GameError::DictionaryError)?
+            .unwrap_or_else(|| normalised.to_string());
+        self.answer_pending = false;
+
+        Ok(())
+    }
+
+    /// Picks the result of a guess in Absurdle mode
+    ///
+    /// The words that could still be the answer are grouped by the result the guess
+    /// would be given, and the result of the largest group is returned, so as many
+    /// answers as possible stay open. Ties go to the result revealing the fewest
+    /// correct, then present, letters. The answer is only fixed once a single word is
+    /// left.
+    fn absurdle_result(&mut self, guess_chars: &[char]) -> Result<Vec<LetterResult>, GameError> {
+        let candidates = self.game_options
+            .dictionary
+            .filter_words(&self.completed_guesses())
+            .map_err(|_e| GameError::DictionaryError)?;
+
+        let mut buckets: HashMap<Vec<LetterResult>, Vec<String>> = HashMap::new();
+        for word in candidates {
+            let answer_chars: Vec<char> = word.chars().collect();
+            buckets
+                .entry(score_guess(&answer_chars, guess_chars))
+                .or_default()
+                .push(word);
+        }
+
+        let revealed = |result: &[LetterResult], kind: LetterResult| {
+            result.iter().filter(|r| **r == kind).count()
+        };
+
+        let (result, words) = buckets
+            .into_iter()
+            .max_by(|(a, a_words), (b, b_words)| {
+                a_words.len()
+                    .cmp(&b_words.len())
+                    .then_with(|| revealed(b, LetterResult::Correct).cmp(&revealed(a, LetterResult::Correct)))
+                    .then_with(|| revealed(b, LetterResult::Present).cmp(&revealed(a, LetterResult::Present)))
+                    .then_with(|| b.cmp(a))
+            })
+            .ok_or_else(|| GameError::InternalError("No possible answers left".to_string()))?;
+
+        // Only one word fits every result so far, so it has to be the answer
+        if let [answer] = words.as_slice() {
+            self.commit_answer(answer)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Checks that a guess uses every hint revealed by the previous guesses
+    ///
+    /// Every `Correct` letter must be reused in the same position, and every `Present`
+    /// letter must appear in the guess at least as many times as it has been revealed.
+    fn check_hard_mode(previous: &[Guess], guess_chars: &[char]) -> Result<(), GameError> {
+        for guess in previous {
+            let Some(results) = &guess.result else {
                 continue;
+            };
+            let letters = guess.as_chars();
+
+            // Letters revealed in their correct position must stay there
+            for (i, (letter, result)) in letters.iter().zip(results.iter()).enumerate() {
+                if *result == LetterResult::Correct && guess_chars.get(i) != Some(letter) {
+                    return Err(GameError::HardModeMissingCorrect {
+                        letter: *letter,
+                        position: i + 1,
+                    });
+                }
             }
 
-            // Check if the letter is present elsewhere in the answer
-            if let Some(pos) = answer_chars.iter().position(|&a| a.to_ascii_uppercase() == g) {
-                result[i] = LetterResult::Present;
-                // Mark this character as used
-                answer_chars[pos] = char::MIN;
+            // Letters revealed as present must be included somewhere
+            for (letter, result) in letters.iter().zip(results.iter()) {
+                if *result != LetterResult::Present {
+                    continue;
+                }
+
+                let revealed = letters
+                    .iter()
+                    .zip(results.iter())
+                    .filter(|(c, r)| *c == letter && matches!(r, LetterResult::Correct | LetterResult::Present))
+                    .count();
+
+                let used = guess_chars.iter().filter(|c| *c == letter).count();
+
+                if used < revealed {
+                    return Err(GameError::HardModeMissingPresent(*letter));
+                }
             }
         }
 
-        result
+        Ok(())
+    }
+
+    /// Checks a guess against the answer and returns the result
+    ///
+    /// The answer is normalised the same way as typed letters, so under a policy that
+    /// strips accents an unaccented guess can match an accented answer.
+    fn check_guess(&self, guess_chars: &[char]) -> Vec<LetterResult> {
+        let answer_chars: Vec<char> = self.game_options.dictionary.normalise(&self.answer).chars().collect();
+        score_guess(&answer_chars, guess_chars)
     }
 
     /// Updates the game state based on the guess result
     fn update_game_state(&mut self, guess_idx: u16, result: &[LetterResult]) {
+        let now = Instant::now();
+
         if result.iter().all(|x| *x == LetterResult::Correct) {
             self.game_state = GameState::Won;
+            self.finished_at = Some(now);
         } else if self.game_options.max_guesses - guess_idx - 1 > 0 {
-            // Activate the next guess
+            // Activate the next guess, restarting its clock
             let next_guess = &mut self.guesses[(guess_idx + 1) as usize];
             next_guess.state = GuessState::Active;
+            self.guess_started_at = now;
         } else {
             self.game_state = GameState::Lost;
+            self.finished_at = Some(now);
         }
     }
 }
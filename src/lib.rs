@@ -0,0 +1,20 @@
+//! Core game engine for the Wordle TUI application
+//!
+//! This library holds everything needed to play a game of Wordle without a terminal:
+//! dictionaries, game state and scoring, options, statistics, sharing and a solver for hints. The `tui`
+//! feature adds the terminal interface on top of it, which the `tui-wordle` binary runs.
+
+pub mod config;
+pub mod dictionary;
+pub mod filter;
+pub mod game;
+pub mod manifest;
+pub mod multi;
+pub mod options;
+pub mod share;
+pub mod solver;
+pub mod stats;
+pub mod timed;
+
+#[cfg(feature = "tui")]
+pub mod tui;
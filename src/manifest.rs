@@ -0,0 +1,231 @@
+use crate::dictionary::{dictionary_dirs, AccentPolicy, Dictionary, DictionaryError, KeyboardLayout, MAX_WORD_LENGTH};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the manifest file looked for in each dictionary directory
+pub const MANIFEST_FILE: &str = "dictionaries.toml";
+
+/// Errors that can occur when loading a dictionaries manifest
+#[derive(Debug, Error)]
+pub enum ManifestError {
+    /// Failed to read the manifest file
+    #[error("Failed to read dictionaries manifest {path}: {source}")]
+    FileError { path: PathBuf, source: std::io::Error },
+
+    /// The manifest file could not be parsed
+    #[error("Failed to parse dictionaries manifest {path}: {source}")]
+    ParseError { path: PathBuf, source: toml::de::Error },
+
+    /// A dictionary in the manifest does not list any word lengths
+    #[error("Dictionary '{name}' in {path} has no lengths")]
+    NoLengths { name: String, path: PathBuf },
+
+    /// A dictionary in the manifest lists a word length that cannot be played
+    #[error("Dictionary '{name}' in {path} has length {length}, lengths must be from 1 to {max}", max = MAX_WORD_LENGTH)]
+    InvalidLength { name: String, length: u8, path: PathBuf },
+
+    /// A dictionary in the manifest has no words of one of its lengths
+    #[error("Dictionary '{name}' in {path} has no {length} letter words to guess")]
+    NoWords { name: String, length: u8, path: PathBuf },
+}
+
+/// Keyboard layout as written in a manifest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestKeyboard {
+    #[default]
+    Qwerty,
+    Azerty,
+    Qwertz,
+}
+
+impl From<ManifestKeyboard> for KeyboardLayout {
+    fn from(keyboard: ManifestKeyboard) -> Self {
+        match keyboard {
+            ManifestKeyboard::Qwerty => KeyboardLayout::Qwerty,
+            ManifestKeyboard::Azerty => KeyboardLayout::Azerty,
+            ManifestKeyboard::Qwertz => KeyboardLayout::Qwertz,
+        }
+    }
+}
+
+/// Accent policy as written in a manifest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestAccents {
+    Strip,
+    #[default]
+    Exact,
+}
+
+impl From<ManifestAccents> for AccentPolicy {
+    fn from(accents: ManifestAccents) -> Self {
+        match accents {
+            ManifestAccents::Strip => AccentPolicy::Strip,
+            ManifestAccents::Exact => AccentPolicy::Exact,
+        }
+    }
+}
+
+/// A dictionary declared in a manifest
+///
+/// Paths are relative to the directory holding the manifest, unless they are absolute.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DictionaryEntry {
+    /// Name of the dictionary
+    pub name: String,
+    /// Path to the word list, one word per line
+    pub path: PathBuf,
+    /// Word lengths the dictionary can be played with
+    pub lengths: Vec<u8>,
+    /// Path to a list of common words to choose answers from, instead of the whole word list
+    #[serde(default)]
+    pub answers: Option<PathBuf>,
+    /// Path to a list of additional words accepted as guesses
+    #[serde(default)]
+    pub allowed_guesses: Option<PathBuf>,
+    /// Letters words may contain, any letter is allowed if not given
+    #[serde(default)]
+    pub alphabet: Option<String>,
+    /// Keyboard layout used to display the dictionary
+    #[serde(default)]
+    pub keyboard: ManifestKeyboard,
+    /// How accented letters are treated when guessing
+    #[serde(default)]
+    pub accents: ManifestAccents,
+    /// Language of the words, such as `de` or `en-GB`
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// A manifest declaring dictionaries to load alongside the built-in ones
+///
+/// A manifest is a TOML file with a `[[dictionary]]` table for each dictionary:
+///
+/// ```toml
+/// [[dictionary]]
+/// name = "German"
+/// path = "german.txt"
+/// answers = "german-common.txt"
+/// lengths = [5, 6]
+/// alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÜ"
+/// keyboard = "qwertz"
+/// locale = "de"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Manifest {
+    /// Every dictionary declared in the manifest
+    #[serde(default)]
+    pub dictionary: Vec<DictionaryEntry>,
+}
+
+impl Manifest {
+    /// Loads a manifest from the given file
+    pub fn load(path: &Path) -> Result<Self, ManifestError> {
+        let contents = fs::read_to_string(path).map_err(|source| ManifestError::FileError {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        toml::from_str(&contents).map_err(|source| ManifestError::ParseError {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Creates a dictionary for every length of every entry
+    ///
+    /// Lengths that cannot be played, or that have no words in a word list that could
+    /// be loaded, are rejected.
+    ///
+    /// # Arguments
+    /// * `path` - The path the manifest was loaded from, used to resolve relative paths
+    ///   to absolute ones
+    pub fn dictionaries(&self, path: &Path) -> Result<Vec<Dictionary>, ManifestError> {
+        // Word lists are looked up in every dictionary directory, so a relative base
+        // would be joined onto each of them again
+        let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let base = absolute.parent().unwrap_or(Path::new(""));
+        let mut dictionaries = Vec::new();
+
+        for entry in &self.dictionary {
+            if entry.lengths.is_empty() {
+                return Err(ManifestError::NoLengths {
+                    name: entry.name.clone(),
+                    path: path.to_path_buf(),
+                });
+            }
+
+            let file = base.join(&entry.path);
+            let answers = entry.answers.as_ref().map(|answers| base.join(answers));
+            let guesses = entry.allowed_guesses.as_ref().map(|guesses| base.join(guesses));
+
+            if let Some(length) = entry.lengths.iter().find(|length| !(1..=MAX_WORD_LENGTH).contains(*length)) {
+                return Err(ManifestError::InvalidLength {
+                    name: entry.name.clone(),
+                    length: *length,
+                    path: path.to_path_buf(),
+                });
+            }
+
+            for length in &entry.lengths {
+                let mut dictionary = Dictionary::new(&entry.name, &file.to_string_lossy(), *length)
+                    .with_keyboard(entry.keyboard.into())
+                    .with_accents(entry.accents.into());
+
+                if let Some(answers) = &answers {
+                    dictionary = dictionary.with_answers_file(&answers.to_string_lossy());
+                }
+
+                if let Some(guesses) = &guesses {
+                    dictionary = dictionary.with_guesses_file(&guesses.to_string_lossy());
+                }
+
+                if let Some(alphabet) = &entry.alphabet {
+                    dictionary = dictionary.with_alphabet(alphabet);
+                }
+
+                if let Some(locale) = &entry.locale {
+                    dictionary = dictionary.with_locale(locale);
+                }
+
+                // Missing word lists are reported when the dictionary is played instead
+                if let Err(DictionaryError::WordNotFound) = dictionary.check_answers() {
+                    return Err(ManifestError::NoWords {
+                        name: entry.name.clone(),
+                        length: *length,
+                        path: path.to_path_buf(),
+                    });
+                }
+
+                dictionaries.push(dictionary);
+            }
+        }
+
+        Ok(dictionaries)
+    }
+}
+
+/// Loads the dictionaries declared in the manifest of every dictionary directory
+///
+/// Manifests that cannot be loaded are skipped, and the reason is returned
+/// alongside the dictionaries that were loaded.
+pub fn load_manifests() -> (Vec<Dictionary>, Vec<ManifestError>) {
+    let mut dictionaries = Vec::new();
+    let mut errors = Vec::new();
+
+    for path in dictionary_dirs().into_iter().map(|dir| dir.join(MANIFEST_FILE)) {
+        if !path.is_file() {
+            continue;
+        }
+
+        match Manifest::load(&path).and_then(|manifest| manifest.dictionaries(&path)) {
+            Ok(loaded) => dictionaries.extend(loaded),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    (dictionaries, errors)
+}
@@ -0,0 +1,211 @@
+use crate::game::{GameData, GameError, GameOptions, GameState};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// Most attempts made to find an answer that is not already used by another board
+const MAX_ANSWER_ATTEMPTS: u64 = 100;
+
+/// Gets the number of guesses allowed when playing several boards at once
+///
+/// Each extra board allows one extra guess, so Dordle allows 7 guesses, Quordle 9
+/// and Octordle 13 when `max_guesses` is 6.
+pub fn guess_budget(max_guesses: u16, boards: u8) -> u16 {
+    max_guesses + (boards.max(1) as u16 - 1)
+}
+
+/// A game played on several boards at once, such as Dordle, Quordle or Octordle
+///
+/// Every board has its own answer, and each guess is typed once and scored against
+/// every board that has not been solved yet. The game is won when every board is
+/// solved within the guess budget.
+#[derive(Debug)]
+pub struct MultiGame {
+    /// Overall state of the game
+    pub game_state: GameState,
+    /// The individual boards, each with its own answer
+    pub boards: Vec<GameData>,
+    /// Options the game was created with
+    game_options: GameOptions,
+}
+
+impl MultiGame {
+    /// Creates a new game with `opts.boards` boards, each with a different answer
+    ///
+    /// Each board allows `guess_budget` guesses. If a seed has been set each board
+    /// gets its own seed derived from it. Daily mode is not supported, as every board
+    /// would have the same answer, so every board gets a random answer instead.
+    ///
+    /// # Panics
+    /// Panics if a random word cannot be retrieved from the dictionary, as with
+    /// `GameData::new`.
+    pub fn new(opts: &GameOptions) -> Self {
+        let mut board_opts = opts.clone();
+        board_opts.max_guesses = guess_budget(opts.max_guesses, opts.boards);
+        board_opts.daily = false;
+
+        let mut answers = HashSet::new();
+        let mut boards = Vec::new();
+
+        for board in 0..opts.boards.max(1) as u64 {
+            let mut game = None;
+
+            for attempt in 0..MAX_ANSWER_ATTEMPTS {
+                board_opts.seed = opts
+                    .seed
+                    .map(|seed| seed.wrapping_add((board << 32) + attempt));
+
+                let candidate = GameData::new(&board_opts);
+                let candidate_pending = candidate.is_answer_pending();
+                let is_new = answers.insert(candidate.answer.clone());
+                game = Some(candidate);
+
+                // Small dictionaries may not have enough words, so allow repeats eventually.
+                // Absurdle boards have no answer yet, so there is nothing to compare.
+                if is_new || candidate_pending {
+                    break;
+                }
+            }
+
+            boards.extend(game);
+        }
+
+        Self {
+            game_state: GameState::Active,
+            boards,
+            game_options: opts.clone(),
+        }
+    }
+
+    /// Gets the options the game was created with
+    pub fn options(&self) -> &GameOptions {
+        &self.game_options
+    }
+
+    /// Gets the number of guesses allowed on every board
+    pub fn guess_budget(&self) -> u16 {
+        guess_budget(self.game_options.max_guesses, self.game_options.boards)
+    }
+
+    /// Gets the number of guesses that have been submitted
+    ///
+    /// Solved boards stop taking guesses, so this is the most used by any board.
+    pub fn guesses_used(&self) -> u16 {
+        self.boards.iter().map(GameData::guesses_used).max().unwrap_or(0)
+    }
+
+    /// Gets the time taken so far, or the final time once every board has finished
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.boards.iter().map(|board| board.elapsed(now)).max().unwrap_or_default()
+    }
+
+    /// Gets whether the game was lost by running out of time
+    pub fn timed_out(&self) -> bool {
+        self.boards.iter().any(GameData::timed_out)
+    }
+
+    /// Checks the time limits of every unsolved board, losing the game if either has
+    /// run out
+    ///
+    /// Boards share their clocks, so once one board runs out of time every other
+    /// unsolved board is stopped too.
+    ///
+    /// # Returns
+    /// * `Ok(GameState)` - The new overall state of the game
+    /// * `Err(GameError)` - If an Absurdle answer could not be chosen to reveal
+    pub fn check_time(&mut self, now: Instant) -> Result<GameState, GameError> {
+        if self.game_state != GameState::Active {
+            return Ok(self.game_state);
+        }
+
+        for board in self.active_boards() {
+            board.check_time(now)?;
+        }
+
+        if self.timed_out() {
+            self.active_boards().try_for_each(|board| board.time_out(now))?;
+            self.game_state = GameState::Lost;
+        }
+
+        Ok(self.game_state)
+    }
+
+    /// Gets the boards that have not been solved or lost yet
+    fn active_boards(&mut self) -> impl Iterator<Item = &mut GameData> {
+        self.boards
+            .iter_mut()
+            .filter(|board| board.game_state == GameState::Active)
+    }
+
+    /// Adds a letter to the guess on every unsolved board
+    pub fn add_letter(&mut self, val: char) -> Result<(), GameError> {
+        if self.game_state != GameState::Active {
+            return Err(GameError::NoActiveGame);
+        }
+
+        self.active_boards().try_for_each(|board| board.add_letter(val))
+    }
+
+    /// Deletes the last letter of the guess on every unsolved board
+    pub fn delete_letter(&mut self) -> Result<(), GameError> {
+        if self.game_state != GameState::Active {
+            return Err(GameError::NoActiveGame);
+        }
+
+        self.active_boards().try_for_each(|board| board.delete_letter())
+    }
+
+    /// Moves the cursor one cell to the left on every unsolved board
+    pub fn move_cursor_left(&mut self) -> Result<(), GameError> {
+        self.edit_active_boards(GameData::move_cursor_left)
+    }
+
+    /// Moves the cursor one cell to the right on every unsolved board
+    pub fn move_cursor_right(&mut self) -> Result<(), GameError> {
+        self.edit_active_boards(GameData::move_cursor_right)
+    }
+
+    /// Clears the letter under the cursor on every unsolved board
+    pub fn clear_letter(&mut self) -> Result<(), GameError> {
+        self.edit_active_boards(GameData::clear_letter)
+    }
+
+    /// Clears the guess on every unsolved board
+    pub fn clear_guess(&mut self) -> Result<(), GameError> {
+        self.edit_active_boards(GameData::clear_guess)
+    }
+
+    /// Applies the same edit to the guess on every unsolved board
+    fn edit_active_boards(&mut self, edit: impl Fn(&mut GameData) -> Result<(), GameError>) -> Result<(), GameError> {
+        if self.game_state != GameState::Active {
+            return Err(GameError::NoActiveGame);
+        }
+
+        self.active_boards().try_for_each(edit)
+    }
+
+    /// Submits the current word to every unsolved board
+    ///
+    /// The word is checked against every board before any board is changed, so a
+    /// rejected word leaves the game as it was.
+    ///
+    /// # Returns
+    /// * `Ok(GameState)` - The new overall state of the game
+    /// * `Err(GameError)` - If the word was rejected by any board
+    pub fn submit_word(&mut self) -> Result<GameState, GameError> {
+        if self.game_state != GameState::Active {
+            return Err(GameError::NoActiveGame);
+        }
+
+        self.active_boards().try_for_each(|board| board.validate_guess())?;
+        self.active_boards().try_for_each(|board| board.submit_word().map(|_| ()))?;
+
+        // Boards run out of guesses together, so any lost board loses the game
+        if self.boards.iter().any(|board| board.game_state == GameState::Lost) {
+            self.game_state = GameState::Lost;
+        } else if self.boards.iter().all(|board| board.game_state == GameState::Won) {
+            self.game_state = GameState::Won;
+        }
+
+        Ok(self.game_state)
+    }
+}
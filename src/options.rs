@@ -1,7 +1,9 @@
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
-use crate::dictionary::{get_dictionaries, Dictionary};
-use crate::game::{GameError, GameOptions};
+use crate::dictionary::{get_dictionaries, Dictionary, DictionaryError, Difficulty};
+use crate::timed::{next_choice, GUESS_TIME_LIMITS, SPEED_RUN_LENGTHS, TIME_LIMITS};
+use crate::game::{GameError, GameOptions, BOARD_COUNTS, MAX_GUESSES, MIN_GUESSES};
 
 /// Error type for options operations
 #[derive(Debug, Error)]
@@ -9,34 +11,128 @@ pub enum OptionsError {
     #[error("Dictionary not found")]
     DictionaryNotFound,
 
+    #[error("Absurdle can only be played on one board")]
+    AbsurdleBoards,
+
     #[error("Game error: {0}")]
     GameError(#[from] GameError),
+
+    #[error("{0}")]
+    DictionaryError(#[from] DictionaryError),
 }
 
 /// Represents the state of the options screen
 #[derive(Debug)]
 pub struct OptionData {
     /// Name of the selected dictionary
-    pub(crate) dictionary_name: String,
+    dictionary_name: String,
     /// Length of words in the selected dictionary
-    pub(crate) dictionary_length: u8,
+    dictionary_length: u8,
     /// Maximum number of tries allowed
-    pub(crate) max_tries: u16,
+    max_tries: u16,
+    /// Whether hard mode is enabled
+    hard_mode: bool,
+    /// Whether the daily word is played
+    daily: bool,
+    /// Number of boards played at once
+    boards: u8,
+    /// Whether the answer is chosen adversarially, as in Absurdle
+    absurdle: bool,
+    /// Seconds allowed for the whole game, if it is timed
+    time_limit: Option<u64>,
+    /// Seconds allowed for each guess, if guesses are timed
+    guess_time_limit: Option<u64>,
+    /// Number of words in a speed run, if one is played
+    speed_run: Option<u16>,
+    /// How common the answers are, for dictionaries that give word frequencies
+    difficulty: Difficulty,
     /// Available dictionaries
     dictionaries: Vec<Arc<Dictionary>>,
 }
 
 impl OptionData {
-    /// Creates a new OptionData with default values
-    pub fn new() -> Self {
+    /// Creates a new OptionData showing the given game options
+    pub fn new(game_options: &GameOptions) -> Self {
         Self {
-            dictionary_name: String::from("Wordle"),
-            dictionary_length: 5,
-            max_tries: 6,
+            dictionary_name: game_options.dictionary.name.clone(),
+            dictionary_length: game_options.dictionary.length,
+            max_tries: game_options.max_guesses,
+            hard_mode: game_options.hard_mode,
+            daily: game_options.daily,
+            boards: game_options.boards,
+            absurdle: game_options.absurdle,
+            time_limit: game_options.time_limit.map(|limit| limit.as_secs()),
+            guess_time_limit: game_options.guess_time_limit.map(|limit| limit.as_secs()),
+            speed_run: game_options.speed_run,
+            difficulty: game_options.difficulty,
             dictionaries: get_dictionaries()
         }
     }
 
+    /// Gets the name of the selected dictionary
+    pub fn dictionary_name(&self) -> &str {
+        &self.dictionary_name
+    }
+
+    /// Gets the length of words in the selected dictionary
+    pub fn dictionary_length(&self) -> u8 {
+        self.dictionary_length
+    }
+
+    /// Gets the maximum number of tries allowed
+    pub fn max_tries(&self) -> u16 {
+        self.max_tries
+    }
+
+    /// Gets whether hard mode is enabled
+    pub fn hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Gets whether the daily word is played
+    pub fn daily(&self) -> bool {
+        self.daily
+    }
+
+    /// Gets the number of boards played at once
+    pub fn boards(&self) -> u8 {
+        self.boards
+    }
+
+    /// Gets whether the answer is chosen adversarially, as in Absurdle
+    pub fn absurdle(&self) -> bool {
+        self.absurdle
+    }
+
+    /// Gets the seconds allowed for the whole game, if it is timed
+    pub fn time_limit(&self) -> Option<u64> {
+        self.time_limit
+    }
+
+    /// Gets the seconds allowed for each guess, if guesses are timed
+    pub fn guess_time_limit(&self) -> Option<u64> {
+        self.guess_time_limit
+    }
+
+    /// Gets the number of words in a speed run, if one is played
+    pub fn speed_run(&self) -> Option<u16> {
+        self.speed_run
+    }
+
+    /// Gets how common the answers are, for dictionaries that give word frequencies
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    /// Checks whether the selected dictionary gives word frequencies, without which
+    /// the difficulty has no effect
+    pub fn difficulty_available(&self) -> bool {
+        self.find_dictionary_index()
+            .ok()
+            .and_then(|idx| self.dictionaries[idx].has_frequencies().ok())
+            .unwrap_or(false)
+    }
+
     /// Finds the current dictionary index
     fn find_dictionary_index(&self) -> Result<usize, OptionsError> {
         self.dictionaries
@@ -71,20 +167,80 @@ impl OptionData {
     }
 
     /// Applies the current options to the game
+    ///
+    /// Nothing is changed if the selected dictionary's word list cannot be loaded or
+    /// has no words to guess, or if Absurdle is selected with more than one board.
     pub fn apply(&self, game_options: &mut GameOptions) -> Result<(), OptionsError> {
+        // Every board would see the same guesses and keep the same answers
+        if self.absurdle && self.boards > 1 {
+            return Err(OptionsError::AbsurdleBoards);
+        }
+
+        let idx = self.find_dictionary_index()?;
+        self.dictionaries[idx].check_answers()?;
+
         game_options.set_dictionary(&self.dictionary_name, self.dictionary_length)?;
         game_options.max_guesses = self.max_tries;
+        game_options.hard_mode = self.hard_mode;
+        game_options.daily = self.daily;
+        game_options.boards = self.boards;
+        game_options.absurdle = self.absurdle;
+        game_options.time_limit = self.time_limit.map(Duration::from_secs);
+        game_options.guess_time_limit = self.guess_time_limit.map(Duration::from_secs);
+        game_options.speed_run = self.speed_run;
+        game_options.difficulty = self.difficulty;
 
         Ok(())
     }
 
-    /// Increments the maximum number of tries (up to 10)
+    /// Increments the maximum number of tries (up to `MAX_GUESSES`)
     pub fn increment_tries(&mut self) {
-        self.max_tries = (self.max_tries + 1).min(10);
+        self.max_tries = (self.max_tries + 1).min(MAX_GUESSES);
     }
 
-    /// Decrements the maximum number of tries (down to 3)
+    /// Decrements the maximum number of tries (down to `MIN_GUESSES`)
     pub fn decrement_tries(&mut self) {
-        self.max_tries = (self.max_tries - 1).max(3);
+        self.max_tries = (self.max_tries - 1).max(MIN_GUESSES);
+    }
+
+    /// Toggles hard mode on or off
+    pub fn toggle_hard_mode(&mut self) {
+        self.hard_mode = !self.hard_mode;
+    }
+
+    /// Toggles daily mode on or off
+    pub fn toggle_daily(&mut self) {
+        self.daily = !self.daily;
+    }
+
+    /// Toggles Absurdle mode on or off
+    pub fn toggle_absurdle(&mut self) {
+        self.absurdle = !self.absurdle;
+    }
+
+    /// Selects the next game time limit in `TIME_LIMITS`, or turns it off after the last
+    pub fn cycle_time_limit(&mut self) {
+        self.time_limit = next_choice(self.time_limit, &TIME_LIMITS);
+    }
+
+    /// Selects the next guess time limit in `GUESS_TIME_LIMITS`, or turns it off after the last
+    pub fn cycle_guess_time_limit(&mut self) {
+        self.guess_time_limit = next_choice(self.guess_time_limit, &GUESS_TIME_LIMITS);
+    }
+
+    /// Selects the next speed run length in `SPEED_RUN_LENGTHS`, or turns it off after the last
+    pub fn cycle_speed_run(&mut self) {
+        self.speed_run = next_choice(self.speed_run, &SPEED_RUN_LENGTHS);
+    }
+
+    /// Selects the next difficulty, wrapping back to the easiest
+    pub fn cycle_difficulty(&mut self) {
+        self.difficulty = self.difficulty.next();
+    }
+
+    /// Selects the next number of boards in `BOARD_COUNTS`, wrapping back to one
+    pub fn cycle_boards(&mut self) {
+        let idx = BOARD_COUNTS.iter().position(|&count| count == self.boards).unwrap_or(0);
+        self.boards = BOARD_COUNTS[(idx + 1) % BOARD_COUNTS.len()];
     }
 }
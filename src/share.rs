@@ -0,0 +1,119 @@
+use crate::game::{GameData, GameState, LetterResult};
+use crate::multi::MultiGame;
+use crate::stats::GameMode;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::io;
+use std::io::Write;
+
+/// Builds the spoiler-free result grid for a finished game
+///
+/// The first line is a header naming the dictionary and the score, e.g.
+/// `Wordle (Scrabble 6) 4/6`, using `X` as the score for a lost game. Each following
+/// line is one completed guess drawn with coloured blocks.
+///
+/// # Returns
+/// The shareable text, or None if the game is still active
+pub fn share_text(game: &GameData) -> Option<String> {
+    let score = match game.game_state {
+        GameState::Won => game.guesses_used().to_string(),
+        GameState::Lost => String::from("X"),
+        GameState::Active => return None,
+    };
+
+    let options = game.options();
+
+    let mode = if options.absurdle { "Absurdle" } else { "Wordle" };
+
+    let mut header = format!(
+        "{} ({} {}) {}/{}",
+        mode,
+        options.dictionary.name, options.dictionary.length, score, options.max_guesses
+    );
+
+    if let Some(date) = game.daily {
+        header.push_str(&format!(" {}", date.format("%Y-%m-%d")));
+    }
+
+    if options.hard_mode {
+        header.push('*');
+    }
+
+    let mut lines = vec![header, String::new()];
+    lines.extend(result_rows(game));
+
+    Some(lines.join("\n"))
+}
+
+/// Builds the spoiler-free result grids for a finished multi-board game
+///
+/// The header names the game after its number of boards, e.g.
+/// `Quordle (Wordle 5) 8/9`, and is followed by the score of each board and then
+/// the grid of each board in turn, separated by blank lines.
+///
+/// # Returns
+/// The shareable text, or None if the game is still active
+pub fn multi_share_text(game: &MultiGame) -> Option<String> {
+    let score = |state: GameState, guesses: u16| match state {
+        GameState::Won => Some(guesses.to_string()),
+        GameState::Lost => Some(String::from("X")),
+        GameState::Active => None,
+    };
+
+    let options = game.options();
+
+    let mode = GameMode::from_options(options).name();
+
+    let header = format!(
+        "{} ({} {}) {}/{}",
+        mode,
+        options.dictionary.name,
+        options.dictionary.length,
+        score(game.game_state, game.guesses_used())?,
+        game.guess_budget()
+    );
+
+    let scores: Vec<String> = game
+        .boards
+        .iter()
+        .map(|board| score(board.game_state, board.guesses_used()).unwrap_or_else(|| String::from("X")))
+        .collect();
+
+    let mut lines = vec![header, scores.join(" ")];
+
+    for board in &game.boards {
+        lines.push(String::new());
+        lines.extend(result_rows(board));
+    }
+
+    Some(lines.join("\n"))
+}
+
+/// Draws each completed guess of a game as a row of coloured blocks
+fn result_rows(game: &GameData) -> Vec<String> {
+    game.guesses
+        .iter()
+        .take(game.guesses_used() as usize)
+        .map(|guess| {
+            guess
+                .values()
+                .iter()
+                .map(|(_, result)| match result {
+                    Some(LetterResult::Correct) => '🟩',
+                    Some(LetterResult::Present) => '🟨',
+                    _ => '⬛',
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Copies text to the system clipboard using the OSC 52 terminal escape sequence
+///
+/// This works with any terminal that supports OSC 52, including over SSH, as the
+/// terminal rather than the application is responsible for the clipboard.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()
+}
@@ -0,0 +1,142 @@
+use crate::dictionary::DictionaryError;
+use crate::filter::Constraint;
+use crate::game::{score_guess, GameData};
+use std::collections::{HashMap, HashSet};
+
+/// Largest number of words considered as the suggested guess
+///
+/// Ranking every guess against every candidate is quadratic, so large word lists are
+/// sampled down to keep hints responsive.
+const MAX_GUESS_POOL: usize = 300;
+/// Largest number of candidates used to estimate the information of a guess
+const MAX_CANDIDATE_SAMPLE: usize = 2000;
+
+/// A hint for the next guess
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hint {
+    /// Number of words that are still possible answers
+    pub remaining: usize,
+    /// Guess expected to reveal the most information, if any words remain
+    pub suggestion: Option<String>,
+}
+
+/// Checks whether a word could be the answer given the results of previous guesses
+///
+/// A word is consistent if scoring each previous guess against it gives exactly the
+/// result that guess was given. This is the definition `WordFilter` implements more
+/// cheaply for whole word lists.
+pub fn is_consistent(word: &[char], constraints: &[Constraint]) -> bool {
+    constraints
+        .iter()
+        .all(|(guess, result)| score_guess(word, guess) == *result)
+}
+
+/// Encodes the result of scoring `guess` against `answer` as a single number
+fn pattern(answer: &[char], guess: &[char]) -> u64 {
+    score_guess(answer, guess)
+        .iter()
+        .fold(0, |code, result| code * 4 + *result as u64)
+}
+
+/// Calculates the expected information, in bits, revealed by a guess
+///
+/// The candidates are partitioned by the result the guess would be given if each were
+/// the answer, and the entropy of the partition sizes is returned.
+pub fn entropy(guess: &[char], candidates: &[Vec<char>]) -> f64 {
+    let mut buckets: HashMap<u64, usize> = HashMap::new();
+
+    for candidate in candidates {
+        *buckets.entry(pattern(candidate, guess)).or_insert(0) += 1;
+    }
+
+    let total = candidates.len() as f64;
+
+    buckets
+        .values()
+        .map(|count| {
+            let p = *count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Takes an evenly spaced sample of at most `max` items
+fn sample<T: Clone>(items: &[T], max: usize) -> Vec<T> {
+    if items.len() <= max {
+        return items.to_vec();
+    }
+
+    let step = items.len().div_ceil(max);
+    items.iter().step_by(step).cloned().collect()
+}
+
+/// Ranks guesses by the expected information they reveal, best first
+///
+/// Guesses that could themselves be the answer are preferred when the information is
+/// equal.
+///
+/// # Arguments
+/// * `guesses` - The words that may be guessed
+/// * `candidates` - The words that are still possible answers
+pub fn rank_guesses(guesses: &[Vec<char>], candidates: &[Vec<char>]) -> Vec<(Vec<char>, f64)> {
+    let mut ranked: Vec<(Vec<char>, f64, bool)> = guesses
+        .iter()
+        .map(|guess| (guess.clone(), entropy(guess, candidates), candidates.contains(guess)))
+        .collect();
+
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.2.cmp(&a.2)));
+
+    ranked
+        .into_iter()
+        .map(|(guess, entropy, _)| (guess, entropy))
+        .collect()
+}
+
+/// Suggests the next guess for a game
+///
+/// The remaining candidates are every dictionary word consistent with the guesses made
+/// so far. In hard mode only candidates are suggested, as any other guess could be
+/// rejected.
+pub fn hint(game: &GameData) -> Result<Hint, DictionaryError> {
+    let options = game.options();
+    let constraints = game.completed_guesses();
+
+    let candidates: Vec<Vec<char>> = options
+        .dictionary
+        .filter_words(&constraints)?
+        .iter()
+        .map(|word| word.chars().collect())
+        .collect();
+
+    let suggestion = if candidates.len() <= 2 {
+        candidates.first().cloned()
+    } else {
+        let pool = if options.hard_mode {
+            candidates.clone()
+        } else {
+            options
+                .dictionary
+                .guess_words()?
+                .iter()
+                .map(|word| word.chars().collect())
+                .collect()
+        };
+
+        // Always consider the candidates themselves alongside a sample of other words
+        let mut guesses = sample(&candidates, MAX_GUESS_POOL / 2);
+        guesses.extend(sample(&pool, MAX_GUESS_POOL / 2));
+        // Candidates can be sampled from both lists, so remove every repeat, not just adjacent ones
+        let mut seen = HashSet::new();
+        guesses.retain(|guess| seen.insert(guess.clone()));
+
+        rank_guesses(&guesses, &sample(&candidates, MAX_CANDIDATE_SAMPLE))
+            .into_iter()
+            .next()
+            .map(|(guess, _)| guess)
+    };
+
+    Ok(Hint {
+        remaining: candidates.len(),
+        suggestion: suggestion.map(|word| word.into_iter().collect()),
+    })
+}
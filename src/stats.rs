@@ -0,0 +1,418 @@
+use crate::game::{GameData, GameOptions, GameState};
+use crate::multi::MultiGame;
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Errors that can occur when loading or saving player statistics
+#[derive(Debug, Error)]
+pub enum StatsError {
+    /// Failed to read or write the stats file
+    #[error("Failed to access stats file: {0}")]
+    FileError(#[from] std::io::Error),
+
+    /// The stats file could not be parsed
+    #[error("Failed to parse stats file: {0}")]
+    ParseError(#[from] toml::de::Error),
+
+    /// The stats could not be serialized
+    #[error("Failed to serialize stats: {0}")]
+    SerializeError(#[from] toml::ser::Error),
+
+    /// The game has not finished yet so cannot be recorded
+    #[error("Cannot record a game that is still active")]
+    GameNotFinished,
+}
+
+/// Outcome of a finished game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameOutcome {
+    Won,
+    Lost,
+}
+
+/// Way a game is played, each summarised separately as they are scored differently
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    /// A single board with the answer chosen up front
+    #[default]
+    Classic,
+    /// A single board with the answer chosen adversarially
+    Absurdle,
+    /// The given number of boards played at once
+    Boards(u8),
+}
+
+impl GameMode {
+    /// Gets the mode of games played on the given number of boards
+    fn new(boards: u8, absurdle: bool) -> Self {
+        match (boards, absurdle) {
+            (0 | 1, false) => GameMode::Classic,
+            (0 | 1, true) => GameMode::Absurdle,
+            (boards, _) => GameMode::Boards(boards),
+        }
+    }
+
+    /// Gets the mode of games played with the given options
+    pub fn from_options(options: &GameOptions) -> Self {
+        Self::new(options.boards, options.absurdle)
+    }
+
+    /// Gets the name of the mode, such as `Wordle` or `Quordle`
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "Wordle",
+            GameMode::Absurdle => "Absurdle",
+            GameMode::Boards(2) => "Dordle",
+            GameMode::Boards(4) => "Quordle",
+            GameMode::Boards(8) => "Octordle",
+            GameMode::Boards(_) => "Multi-board Wordle",
+        }
+    }
+}
+
+/// Record of a single finished game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRecord {
+    /// Name of the dictionary the game was played with
+    pub dictionary: String,
+    /// Length of the words in the dictionary
+    pub length: u8,
+    /// Maximum number of guesses allowed in the game
+    pub max_guesses: u16,
+    /// Number of guesses the player made
+    pub guesses: u16,
+    /// Whether the game was won or lost
+    pub outcome: GameOutcome,
+    /// Number of hints the player asked for
+    #[serde(default)]
+    pub hints: u16,
+    /// Date of the daily puzzle, if the game was a daily game
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily: Option<NaiveDate>,
+    /// Whether the answer was chosen adversarially, as in Absurdle
+    #[serde(default)]
+    pub absurdle: bool,
+    /// Number of boards played at once
+    #[serde(default = "default_boards")]
+    pub boards: u8,
+}
+
+/// Number of boards used by stats files saved before multi-board games were recorded
+fn default_boards() -> u8 {
+    1
+}
+
+impl GameRecord {
+    /// Gets the mode the game was played in
+    pub fn mode(&self) -> GameMode {
+        GameMode::new(self.boards, self.absurdle)
+    }
+
+    /// Creates a record from a finished game
+    ///
+    /// # Returns
+    /// * `Ok(GameRecord)` - The record for the game
+    /// * `Err(StatsError::GameNotFinished)` - If the game is still active
+    pub fn from_game(game: &GameData) -> Result<Self, StatsError> {
+        let outcome = match game.game_state {
+            GameState::Won => GameOutcome::Won,
+            GameState::Lost => GameOutcome::Lost,
+            GameState::Active => return Err(StatsError::GameNotFinished),
+        };
+
+        let options = game.options();
+
+        Ok(Self {
+            dictionary: options.dictionary.name.clone(),
+            length: options.dictionary.length,
+            max_guesses: options.max_guesses,
+            guesses: game.guesses_used(),
+            outcome,
+            hints: game.hints_used,
+            daily: game.daily,
+            absurdle: options.absurdle,
+            boards: 1,
+        })
+    }
+
+    /// Creates a single record from a finished multi-board game
+    ///
+    /// The number of guesses is the number made before the last board was solved or
+    /// the game was lost, and the maximum is the guess budget shared by every board.
+    ///
+    /// # Returns
+    /// * `Ok(GameRecord)` - The record for the game
+    /// * `Err(StatsError::GameNotFinished)` - If the game is still active
+    pub fn from_multi(game: &MultiGame) -> Result<Self, StatsError> {
+        let outcome = match game.game_state {
+            GameState::Won => GameOutcome::Won,
+            GameState::Lost => GameOutcome::Lost,
+            GameState::Active => return Err(StatsError::GameNotFinished),
+        };
+
+        let options = game.options();
+
+        Ok(Self {
+            dictionary: options.dictionary.name.clone(),
+            length: options.dictionary.length,
+            max_guesses: game.guess_budget(),
+            guesses: game.guesses_used(),
+            outcome,
+            hints: 0,
+            daily: None,
+            absurdle: options.absurdle,
+            boards: options.boards,
+        })
+    }
+}
+
+/// A finished time on the leaderboard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeRecord {
+    /// Name of the dictionary the time was set with
+    pub dictionary: String,
+    /// Length of the words in the dictionary
+    pub length: u8,
+    /// Number of words solved, more than one for a speed run
+    pub words: u16,
+    /// Time taken, in milliseconds
+    pub millis: u64,
+    /// Date the time was set
+    pub date: NaiveDate,
+}
+
+impl TimeRecord {
+    /// Gets the time taken
+    pub fn time(&self) -> Duration {
+        Duration::from_millis(self.millis)
+    }
+}
+
+/// Summary of the statistics for a single dictionary
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StatsSummary {
+    /// Number of games played
+    pub played: u32,
+    /// Number of games won
+    pub won: u32,
+    /// Number of games won with the help of hints
+    pub assisted_wins: u32,
+    /// Number of consecutive wins in the most recent games
+    pub current_streak: u32,
+    /// Longest run of consecutive wins
+    pub max_streak: u32,
+    /// Number of games won with each number of guesses, starting from one guess
+    pub distribution: Vec<u32>,
+}
+
+impl StatsSummary {
+    /// Gets the percentage of games won, rounded down
+    pub fn win_percentage(&self) -> u32 {
+        (self.won * 100).checked_div(self.played).unwrap_or(0)
+    }
+}
+
+/// Persistent statistics for every game the player has finished
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Stats {
+    /// Every finished game, oldest first
+    #[serde(default)]
+    pub games: Vec<GameRecord>,
+    /// Finished times of timed games and speed runs, oldest first
+    #[serde(default)]
+    pub times: Vec<TimeRecord>,
+}
+
+impl Stats {
+    /// Gets the default location of the stats file in the user's data directory
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("tui-wordle").join("stats.toml"))
+    }
+
+    /// Loads the stats from the given file, returning empty stats if it does not exist
+    pub fn load(path: &Path) -> Result<Self, StatsError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Saves the stats to the given file, creating its directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), StatsError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Records a finished game
+    ///
+    /// Replays of a daily puzzle that has already been recorded are ignored, so only
+    /// the first attempt at each daily puzzle counts.
+    pub fn record(&mut self, game: &GameData) -> Result<(), StatsError> {
+        let record = GameRecord::from_game(game)?;
+
+        if let Some(date) = record.daily {
+            if self.played_daily(&record.dictionary, record.length, date) {
+                return Ok(());
+            }
+        }
+
+        self.games.push(record);
+        Ok(())
+    }
+
+    /// Records a finished multi-board game as a single game
+    pub fn record_multi(&mut self, game: &MultiGame) -> Result<(), StatsError> {
+        self.games.push(GameRecord::from_multi(game)?);
+        Ok(())
+    }
+
+    /// Adds a finished time to the leaderboard
+    ///
+    /// # Arguments
+    /// * `dictionary` - The name of the dictionary
+    /// * `length` - The length of words in the dictionary
+    /// * `words` - The number of words solved in the time
+    /// * `time` - The time taken
+    pub fn record_time(&mut self, dictionary: &str, length: u8, words: u16, time: Duration) {
+        self.times.push(TimeRecord {
+            dictionary: dictionary.to_string(),
+            length,
+            words,
+            millis: time.as_millis() as u64,
+            date: Local::now().date_naive(),
+        });
+    }
+
+    /// Gets the fastest times set with the given dictionary
+    ///
+    /// Times are grouped by the number of words solved, fewest words first, and are
+    /// fastest first within each group. At most `limit` times are returned per group.
+    pub fn best_times(&self, dictionary: &str, length: u8, limit: usize) -> Vec<&TimeRecord> {
+        let mut times: Vec<&TimeRecord> = self
+            .times
+            .iter()
+            .filter(|t| t.dictionary == dictionary && t.length == length)
+            .collect();
+
+        times.sort_by_key(|t| (t.words, t.millis));
+
+        let mut best: Vec<&TimeRecord> = Vec::new();
+        for time in times {
+            if best.iter().filter(|t| t.words == time.words).count() < limit {
+                best.push(time);
+            }
+        }
+
+        best
+    }
+
+    /// Checks whether the daily puzzle for the given dictionary and date has been played
+    ///
+    /// # Arguments
+    /// * `dictionary` - The name of the dictionary
+    /// * `length` - The length of words in the dictionary
+    /// * `date` - The date of the daily puzzle
+    pub fn played_daily(&self, dictionary: &str, length: u8, date: NaiveDate) -> bool {
+        self.games
+            .iter()
+            .any(|g| g.dictionary == dictionary && g.length == length && g.daily == Some(date))
+    }
+
+    /// Gets every dictionary that has been played, in the order they were first played
+    ///
+    /// Dictionaries that only have leaderboard times are listed after the others.
+    pub fn dictionaries(&self) -> Vec<(String, u8)> {
+        let mut dictionaries: Vec<(String, u8)> = Vec::new();
+
+        let games = self.games.iter().map(|g| (&g.dictionary, g.length));
+        let times = self.times.iter().map(|t| (&t.dictionary, t.length));
+
+        for (dictionary, length) in games.chain(times) {
+            let key = (dictionary.clone(), length);
+            if !dictionaries.contains(&key) {
+                dictionaries.push(key);
+            }
+        }
+
+        dictionaries
+    }
+
+    /// Gets every mode played with the given dictionary, in the order they were first
+    /// played
+    ///
+    /// # Arguments
+    /// * `dictionary` - The name of the dictionary
+    /// * `length` - The length of words in the dictionary
+    pub fn modes(&self, dictionary: &str, length: u8) -> Vec<GameMode> {
+        let mut modes = Vec::new();
+
+        for game in self.games.iter().filter(|g| g.dictionary == dictionary && g.length == length) {
+            if !modes.contains(&game.mode()) {
+                modes.push(game.mode());
+            }
+        }
+
+        modes
+    }
+
+    /// Summarises the games played with the given dictionary in one mode
+    ///
+    /// Each mode is summarised separately, as Absurdle answers are chosen differently
+    /// and multi-board games allow more guesses, which would skew the win percentage,
+    /// streaks and guess distribution of the others.
+    ///
+    /// # Arguments
+    /// * `dictionary` - The name of the dictionary
+    /// * `length` - The length of words in the dictionary
+    /// * `mode` - The mode the games were played in
+    pub fn summary(&self, dictionary: &str, length: u8, mode: GameMode) -> StatsSummary {
+        let mut summary = StatsSummary::default();
+
+        for game in self
+            .games
+            .iter()
+            .filter(|g| g.dictionary == dictionary && g.length == length && g.mode() == mode)
+        {
+            summary.played += 1;
+
+            match game.outcome {
+                GameOutcome::Won => {
+                    summary.won += 1;
+                    if game.hints > 0 {
+                        summary.assisted_wins += 1;
+                    }
+                    summary.current_streak += 1;
+                    summary.max_streak = summary.max_streak.max(summary.current_streak);
+
+                    let idx = game.guesses.saturating_sub(1) as usize;
+                    if summary.distribution.len() <= idx {
+                        summary.distribution.resize(idx + 1, 0);
+                    }
+                    summary.distribution[idx] += 1;
+                }
+                GameOutcome::Lost => {
+                    summary.current_streak = 0;
+                }
+            }
+
+            // Always show a bar for every guess that was allowed
+            let max_guesses = game.max_guesses as usize;
+            if summary.distribution.len() < max_guesses {
+                summary.distribution.resize(max_guesses, 0);
+            }
+        }
+
+        summary
+    }
+}
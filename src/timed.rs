@@ -0,0 +1,88 @@
+use std::time::{Duration, Instant};
+
+/// Countdowns, in seconds, that can be chosen for a whole game
+pub const TIME_LIMITS: [u64; 3] = [60, 120, 300];
+/// Countdowns, in seconds, that can be chosen for each guess
+pub const GUESS_TIME_LIMITS: [u64; 3] = [10, 20, 30];
+/// Numbers of words that can be chosen for a speed run
+pub const SPEED_RUN_LENGTHS: [u16; 3] = [3, 5, 10];
+
+/// A run of games played back to back, timed from the first game to the last
+///
+/// Each won game moves the run on to the next word, and losing any game ends the run.
+#[derive(Debug, Clone)]
+pub struct SpeedRun {
+    /// Number of words that must be solved
+    pub target: u16,
+    /// Number of words solved so far
+    pub solved: u16,
+    /// Whether a game was lost, ending the run
+    pub failed: bool,
+    /// When the run started
+    started_at: Instant,
+    /// When the run finished or failed, so the clock stops
+    finished_at: Option<Instant>,
+}
+
+impl SpeedRun {
+    /// Starts a run of `target` words at the given time
+    pub fn new(target: u16, now: Instant) -> Self {
+        Self {
+            target,
+            solved: 0,
+            failed: false,
+            started_at: now,
+            finished_at: None,
+        }
+    }
+
+    /// Gets whether the run has finished, either by solving every word or failing
+    pub fn is_finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+
+    /// Gets the time taken so far, or the final time if the run has finished
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.finished_at
+            .unwrap_or(now)
+            .saturating_duration_since(self.started_at)
+    }
+
+    /// Records a solved word
+    ///
+    /// # Returns
+    /// True if this was the last word, finishing the run
+    pub fn record_win(&mut self, now: Instant) -> bool {
+        self.solved += 1;
+
+        if self.solved >= self.target {
+            self.finished_at = Some(now);
+        }
+
+        self.is_finished()
+    }
+
+    /// Records a lost game, which ends the run
+    pub fn record_loss(&mut self, now: Instant) {
+        self.failed = true;
+        self.finished_at = Some(now);
+    }
+}
+
+/// Formats a duration as minutes and seconds, such as `1:05`
+pub fn format_clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Selects the value after `current` in `choices`, where `None` means the mode is off
+///
+/// The value after the last choice is `None`, and the value after `None` is the
+/// first choice.
+pub fn next_choice<T: Copy + PartialEq>(current: Option<T>, choices: &[T]) -> Option<T> {
+    match current.and_then(|value| choices.iter().position(|choice| *choice == value)) {
+        Some(idx) => choices.get(idx + 1).copied(),
+        None if current.is_some() => None,
+        None => choices.first().copied(),
+    }
+}
@@ -0,0 +1,92 @@
+use ratatui::crossterm::event;
+use ratatui::crossterm::event::{Event, KeyEvent};
+use std::collections::VecDeque;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// How often a tick event is produced when no input is received
+pub const TICK_RATE: Duration = Duration::from_millis(50);
+
+/// Events the application reacts to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppEvent {
+    /// A key was pressed, repeated or released
+    Key(KeyEvent),
+    /// The tick rate has elapsed, time-driven state should be updated
+    Tick,
+    /// The terminal was resized to the given width and height
+    Resize(u16, u16),
+}
+
+/// Produces application events from terminal input and a fixed-rate timer
+#[derive(Debug)]
+pub struct EventHandler {
+    /// Time between tick events
+    tick_rate: Duration,
+    /// When the last tick event was produced
+    last_tick: Instant,
+    /// Events to replay instead of reading the terminal, if scripted
+    script: Option<VecDeque<AppEvent>>,
+}
+
+impl EventHandler {
+    /// Creates a new event handler producing ticks at the given rate
+    pub fn new(tick_rate: Duration) -> Self {
+        Self {
+            tick_rate,
+            last_tick: Instant::now(),
+            script: None,
+        }
+    }
+
+    /// Creates an event handler that replays the given events instead of reading
+    /// the terminal, producing `AppEvent::Tick` once they have all been replayed
+    ///
+    /// Used to drive the application without a real terminal.
+    pub fn scripted(events: impl IntoIterator<Item = AppEvent>) -> Self {
+        Self {
+            script: Some(events.into_iter().collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Checks whether every scripted event has been replayed
+    ///
+    /// Always false when reading events from the terminal.
+    pub fn is_finished(&self) -> bool {
+        self.script.as_ref().is_some_and(VecDeque::is_empty)
+    }
+
+    /// Waits for the next event
+    ///
+    /// Returns the next terminal input if one arrives before the next tick is due,
+    /// otherwise returns `AppEvent::Tick` once the tick rate has elapsed.
+    pub fn next_event(&mut self) -> io::Result<AppEvent> {
+        if let Some(script) = self.script.as_mut() {
+            return Ok(script.pop_front().unwrap_or(AppEvent::Tick));
+        }
+
+        loop {
+            let timeout = self.tick_rate.saturating_sub(self.last_tick.elapsed());
+
+            if event::poll(timeout)? {
+                match event::read()? {
+                    Event::Key(key) => return Ok(AppEvent::Key(key)),
+                    Event::Resize(width, height) => return Ok(AppEvent::Resize(width, height)),
+                    _ => {}
+                }
+            }
+
+            if self.last_tick.elapsed() >= self.tick_rate {
+                self.last_tick = Instant::now();
+                return Ok(AppEvent::Tick);
+            }
+        }
+    }
+}
+
+impl Default for EventHandler {
+    fn default() -> Self {
+        Self::new(TICK_RATE)
+    }
+}
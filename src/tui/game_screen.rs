@@ -0,0 +1,642 @@
+use crate::game::{GameData, GameOptions, GameState, LetterResult};
+use crate::multi::MultiGame;
+use crate::timed::{format_clock, SpeedRun};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::canvas::{Canvas, Rectangle};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tui_big_text::{BigText, PixelSize};
+
+/// How long a toast message stays on screen
+pub const TOAST_DURATION: Duration = Duration::from_secs(2);
+/// How long a hint stays on screen
+pub const HINT_DURATION: Duration = Duration::from_secs(5);
+/// How long a notice about a newly started game stays on screen
+pub const NOTICE_DURATION: Duration = Duration::from_secs(5);
+
+/// A transient message shown to the player above the guess grid
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// The message to display
+    pub text: String,
+    /// Whether the active row should be highlighted as rejected
+    pub shake: bool,
+    /// When the message was first shown
+    shown_at: Instant,
+    /// How long the message is shown for
+    duration: Duration,
+}
+
+impl Toast {
+    /// Creates a new toast that is shown from now for `TOAST_DURATION`
+    pub fn new(text: String, shake: bool) -> Self {
+        Self::with_duration(text, shake, TOAST_DURATION)
+    }
+
+    /// Creates a new toast that is shown from now for the given duration
+    pub fn with_duration(text: String, shake: bool, duration: Duration) -> Self {
+        Self {
+            text,
+            shake,
+            shown_at: Instant::now(),
+            duration,
+        }
+    }
+
+    /// Checks whether the toast has been shown for longer than its duration
+    pub fn is_expired(&self) -> bool {
+        self.shown_at.elapsed() >= self.duration
+    }
+}
+
+/// Configuration options for rendering the game
+#[derive(Debug)]
+pub struct RenderOpts {
+    /// The width of the grid lines in pixels
+    grid_line_width: u16,
+
+    /// The offset of the start of the grid from the left edge of the screen
+    grid_left_border: u16,
+    /// The offset of the end of the grid from the right edge of the screen
+    grid_right_border: u16,
+
+    /// The offset of the start of the grid from the top edge of the screen
+    grid_top_border: u16,
+    /// The offset of the end of the grid from the bottom edge of the screen
+    grid_bottom_border: u16,
+
+    /// The width of each letter cell
+    pub letter_cell_width: u16,
+    /// The height of each letter cell
+    pub letter_cell_height: u16,
+
+    /// The spacing between letter boxes
+    pub box_spacing: u16,
+
+    /// The background color of the game screen
+    pub background_colour: Color,
+    /// The color of the grid lines
+    pub grid_colour: Color,
+
+    /// Mapping of letter results to background colors
+    cell_background_colours: HashMap<LetterResult, Option<Color>>,
+
+    /// Background color of keyboard keys for letters known to be absent
+    pub absent_key_colour: Color,
+
+    /// The color of the cells in a row whose guess was rejected
+    pub shake_colour: Color,
+    /// The color of every cell of a grid that has been greyed out
+    pub dimmed_colour: Color,
+    /// The color of the cell under the cursor
+    pub cursor_colour: Color,
+}
+impl RenderOpts {
+    /// Gets the background color for a letter result
+    ///
+    /// # Arguments
+    /// * `letter_result` - The letter result to get the background color for
+    ///
+    /// # Returns
+    /// The background color for the letter result, or None if no color is defined
+    pub fn background_colour(&self, letter_result: &LetterResult) -> Option<Color> {
+        self.cell_background_colours
+            .get(letter_result)
+            .copied()
+            .flatten() // Simplify by flattening Option<Option<Color>> to Option<Color>
+    }
+
+    /// Creates a new RenderOpts instance sized for the given area
+    ///
+    /// # Arguments
+    /// * `game_options` - The game options to use for sizing
+    /// * `area` - The area to size the render options for
+    ///
+    /// # Returns
+    /// A new RenderOpts instance sized for the given area
+    pub fn for_rect(game_options: &GameOptions, area: &Rect) -> Self {
+        let mut render_opts = RenderOpts {
+            background_colour: Color::Rgb(255, 255, 255),
+            grid_colour: Color::Rgb(211, 214, 218),
+            grid_bottom_border: 1,
+            grid_top_border: 1,
+            grid_left_border: 1,
+            grid_right_border: 1,
+
+            grid_line_width: 1,
+            box_spacing: 2,
+
+            letter_cell_height: 6,
+            letter_cell_width: 6,
+
+            cell_background_colours: HashMap::from([
+                (LetterResult::Correct, Some(Color::LightGreen)),
+                (LetterResult::Empty, None),
+                (LetterResult::Absent, None),
+                (LetterResult::Present, Some(Color::LightYellow)),
+            ]),
+
+            absent_key_colour: Color::DarkGray,
+            shake_colour: Color::LightRed,
+            dimmed_colour: Color::DarkGray,
+            cursor_colour: Color::LightBlue,
+        };
+
+        // Always calculate the optimal cell size based on available space
+        // Calculate available width and height for cells
+        let available_width = (area.width.saturating_sub(
+            render_opts.grid_left_border + 
+            render_opts.grid_right_border + 
+            (game_options.word_length - 1) * render_opts.box_spacing +
+            game_options.word_length * 2 * render_opts.grid_line_width
+        )) as f32;
+
+        let available_height = (area.height.saturating_sub(
+            render_opts.grid_top_border + 
+            render_opts.grid_bottom_border + 
+            (game_options.max_guesses - 1) * render_opts.box_spacing +
+            game_options.max_guesses * 2 * render_opts.grid_line_width
+        )) as f32;
+
+        // Calculate cell width and height based on available space
+        let cell_width = (available_width / game_options.word_length as f32).max(1.0) as u16;
+        let cell_height = (available_height / game_options.max_guesses as f32).max(1.0) as u16;
+
+        // Use the smaller dimension to keep cells square
+        let cell_size = cell_width.min(cell_height);
+
+        render_opts.letter_cell_width = cell_size;
+        render_opts.letter_cell_height = cell_size;
+
+        render_opts
+    }
+}
+
+/// Draws the game screen
+///
+/// # Arguments
+/// * `frame` - The frame to draw on
+/// * `game_options` - The game options
+/// * `game_data` - The game data
+/// * `speed_run` - The speed run being played, if any
+/// * `toast` - A message to show the player, if any
+pub fn draw_game(
+    frame: &mut Frame,
+    game_options: &GameOptions,
+    game_data: &GameData,
+    speed_run: Option<&SpeedRun>,
+    toast: Option<&Toast>,
+) {
+    // Expired messages are no longer shown
+    let toast = toast.filter(|t| !t.is_expired());
+
+    // Split the screen into a content area and a status bar
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Percentage(98), Constraint::Percentage(2)])
+        .split(frame.area());
+
+    let content_panel = layout[0];
+    let status_bar_panel = layout[1];
+
+    // Create the status bar with controls and dictionary info
+    let controls = match game_data.game_state {
+        GameState::Active => "New Game: CTRL-N, Quit: CTRL-Q | ESC, Options: CTRL-O, Stats: CTRL-S, Hint: TAB",
+        _ => "New Game: CTRL-N, Quit: CTRL-Q | ESC, Options: CTRL-O, Stats: CTRL-S, Share: CTRL-Y",
+    };
+
+    // Keep the dictionary short, as the clock shares the line with the controls
+    let dictionary = format!("{} {}", game_options.dictionary.name, game_options.dictionary.length);
+
+    let mut title = match game_data.daily {
+        Some(date) => format!("Daily {}, {}", date.format("%Y-%m-%d"), dictionary),
+        None if game_options.absurdle => format!("Absurdle, {}", dictionary),
+        None => dictionary,
+    };
+
+    if let Some(clock) = clock_text(game_data, speed_run, Instant::now()) {
+        title = format!("{} | {}", clock, title);
+    }
+
+    draw_status_bar(frame, status_bar_panel, controls, title);
+
+    // Split off a message line, shown whatever the state of the game
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1), Constraint::Fill(1)])
+        .split(content_panel);
+
+    let toast_panel = layout[0];
+    let content_panel = layout[1];
+
+    if let Some(toast) = toast {
+        frame.render_widget(
+            Paragraph::new(Line::from(toast.text.as_str().bold()).centered()),
+            toast_panel,
+        );
+    }
+
+    match game_data.game_state {
+        GameState::Won => {
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Fill(1), Constraint::Percentage(30), Constraint::Fill(1)])
+                .split(content_panel);
+
+            frame.render_widget(
+                BigText::builder()
+                    .pixel_size(PixelSize::Full)
+                    .style(Style::new())
+                    .lines(vec![Line::from("You Won!".green())])
+                    .centered()
+                    .build(),
+                layout[1],
+            );
+        }
+        GameState::Lost => {
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Fill(1), Constraint::Percentage(30), Constraint::Percentage(30), Constraint::Fill(1)])
+                .split(content_panel);
+
+            frame.render_widget(
+                BigText::builder()
+                    .pixel_size(PixelSize::Full)
+                    .lines(vec![Line::from(if game_data.timed_out() { "Out of Time!" } else { "You Lost!" }.red())])
+                    .centered()
+                    .build(),
+                layout[1],
+            );
+
+            frame.render_widget(
+                BigText::builder()
+                    .pixel_size(PixelSize::Quadrant)
+                    .lines(vec![Line::from(
+                        format!("The word was {}", game_data.answer.to_uppercase()).white(),
+                    )])
+                    .centered()
+                    .build(),
+                layout[2],
+            );
+        }
+        _ => {
+            // Split the content into the guess grid and the keyboard
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Fill(1), Constraint::Length(KEYBOARD_HEIGHT)])
+                .split(content_panel);
+
+            let content_panel = layout[0];
+            let keyboard_panel = layout[1];
+
+            // The row to highlight if the last submission was rejected
+            let shake_row = toast
+                .filter(|t| t.shake)
+                .map(|_| game_data.guesses_used());
+
+            let style = GridStyle {
+                shake_row,
+                show_remaining: true,
+                dimmed: false,
+            };
+
+            // there's a minimum size we can't render below, so skip the rest of the screen
+            let Some(render_opts) = draw_grid(frame, content_panel, game_data, style) else {
+                return;
+            };
+
+            draw_keyboard(frame, keyboard_panel, game_options, game_data, &render_opts);
+        }
+    }
+}
+
+/// Draws a status bar with the controls on the left and a title on the right
+///
+/// The title is never cut off, so the controls are shortened instead when the
+/// line is too narrow for both.
+///
+/// # Arguments
+/// * `frame` - The frame to draw on
+/// * `area` - The area of the status bar
+/// * `controls` - The controls to list
+/// * `title` - The title, such as the dictionary and clock
+fn draw_status_bar(frame: &mut Frame, area: Rect, controls: &str, title: String) {
+    let title = Line::from(title);
+
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Fill(1), Constraint::Length(title.width() as u16 + 1)])
+        .split(area);
+
+    frame.render_widget(Block::default().title(Line::from(controls).left_aligned()), layout[0]);
+    frame.render_widget(Block::default().title(title.right_aligned()), layout[1]);
+}
+
+/// Describes the clocks of a timed game for the status bar
+///
+/// # Returns
+/// The time taken and any time left, or None if the game is not timed
+fn clock_text(game_data: &GameData, speed_run: Option<&SpeedRun>, now: Instant) -> Option<String> {
+    if !game_data.options().is_timed() {
+        return None;
+    }
+
+    let mut parts = Vec::new();
+
+    match speed_run {
+        Some(run) => parts.push(format!(
+            "Run {}/{} {}",
+            (run.solved + 1).min(run.target),
+            run.target,
+            format_clock(run.elapsed(now))
+        )),
+        None => parts.push(format!("Time {}", format_clock(game_data.elapsed(now)))),
+    }
+
+    if let Some(remaining) = game_data.time_remaining(now) {
+        parts.push(format!("{} left", format_clock(remaining)));
+    }
+
+    if let Some(remaining) = game_data.guess_time_remaining(now) {
+        parts.push(format!("Guess {}", format_clock(remaining)));
+    }
+
+    Some(parts.join(", "))
+}
+
+/// Most boards drawn side by side before starting a new row of boards
+const MAX_BOARDS_PER_ROW: usize = 4;
+
+/// Draws the game screen for a game played on several boards at once
+///
+/// Solved boards are greyed out, and the answers are shown once the game is over.
+///
+/// # Arguments
+/// * `frame` - The frame to draw on
+/// * `game` - The multi-board game
+/// * `speed_run` - The speed run being played, if any
+/// * `toast` - A message to show the player, if any
+pub fn draw_multi_game(frame: &mut Frame, game: &MultiGame, speed_run: Option<&SpeedRun>, toast: Option<&Toast>) {
+    // Expired messages are no longer shown
+    let toast = toast.filter(|t| !t.is_expired());
+
+    // Split the screen into a message line, the boards and a status bar
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1), Constraint::Fill(1), Constraint::Length(1)])
+        .split(frame.area());
+
+    let message_panel = layout[0];
+    let boards_panel = layout[1];
+    let status_bar_panel = layout[2];
+
+    let controls = "New Game: CTRL-N, Quit: CTRL-Q | ESC, Options: CTRL-O, Stats: CTRL-S";
+    let mut title = format!(
+        "{} boards, {} {}",
+        game.boards.len(),
+        game.options().dictionary.name,
+        game.options().dictionary.length
+    );
+
+    // The board that has been played longest shows the clock of the whole game
+    let now = Instant::now();
+    let clock = game
+        .boards
+        .iter()
+        .max_by_key(|board| board.elapsed(now))
+        .and_then(|board| clock_text(board, speed_run, now));
+
+    if let Some(clock) = clock {
+        title = format!("{} | {}", clock, title);
+    }
+
+    draw_status_bar(frame, status_bar_panel, controls, title);
+
+    let mut message = match game.game_state {
+        GameState::Won => Line::from(format!("Solved all {} boards!", game.boards.len()).green().bold()),
+        GameState::Lost => {
+            let answers: Vec<String> = game.boards.iter().map(|board| board.answer.to_uppercase()).collect();
+            let outcome = if game.timed_out() { "Out of Time!" } else { "You Lost!" };
+            Line::from(format!("{} The words were {}", outcome, answers.join(", ")).red().bold())
+        }
+        _ => Line::default(),
+    };
+
+    // Any message follows the outcome once the game is over
+    if let Some(toast) = toast {
+        if message.width() > 0 {
+            message.push_span(" | ");
+        }
+        message.push_span(toast.text.as_str().bold());
+    }
+
+    frame.render_widget(Paragraph::new(message.centered()), message_panel);
+
+    let per_row = game.boards.len().clamp(1, MAX_BOARDS_PER_ROW);
+    let rows = game.boards.len().div_ceil(per_row);
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(boards_panel);
+
+    for (row, row_area) in row_areas.iter().enumerate() {
+        let board_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, per_row as u32); per_row])
+            .split(*row_area);
+
+        for (board, board_area) in game.boards.iter().skip(row * per_row).zip(board_areas.iter()) {
+            // The row to highlight if the last submission was rejected
+            let shake_row = toast
+                .filter(|t| t.shake && board.game_state == GameState::Active)
+                .map(|_| board.guesses_used());
+
+            let style = GridStyle {
+                shake_row,
+                show_remaining: false,
+                dimmed: board.game_state == GameState::Won,
+            };
+
+            draw_grid(frame, *board_area, board, style);
+        }
+    }
+}
+
+/// How a guess grid should be drawn
+#[derive(Debug, Clone, Copy, Default)]
+struct GridStyle {
+    /// The row to highlight because its guess was rejected
+    shake_row: Option<u16>,
+    /// Whether to show the number of remaining candidates next to each completed row
+    show_remaining: bool,
+    /// Whether to grey out the whole grid, used for solved boards
+    dimmed: bool,
+}
+
+/// Draws the guess grid of a game, sized to fill the given area
+///
+/// # Arguments
+/// * `frame` - The frame to draw on
+/// * `area` - The area to draw the grid in
+/// * `game_data` - The game data
+/// * `style` - How the grid should be drawn
+///
+/// # Returns
+/// The render options used, or None if the area is too small to draw the grid
+fn draw_grid(frame: &mut Frame, area: Rect, game_data: &GameData, style: GridStyle) -> Option<RenderOpts> {
+    let game_options = game_data.options();
+    let remaining_candidates = game_data.remaining_candidates();
+    let cursor = game_data.cursor();
+
+    let render_opts = RenderOpts::for_rect(game_options, &area);
+    // there's a minimum size we can't render below, if we are getting a cell that is zero
+    // or lower, then we should just not even attempt to render.
+    if render_opts.letter_cell_height == 0 || render_opts.letter_cell_width == 0 {
+        return None;
+    }
+
+    let canvas = Canvas::default()
+        .background_color(render_opts.background_colour)
+        .marker(Marker::Block)
+        .x_bounds([0.0, area.width as f64])
+        .y_bounds([0.0, area.height as f64])
+        .paint(|ctx| {
+            for y in 0..game_options.max_guesses {
+                // Flip the index so that the first guess is at the top
+                let guess_idx = game_options.max_guesses - y - 1;
+                let guess = &game_data.guesses[guess_idx as usize].values();
+
+                for x in 0..game_options.word_length {
+                    let letter = &guess[x as usize];
+
+                    let x_cell = render_opts.grid_left_border
+                        + (x * render_opts.letter_cell_width)
+                        + (x * render_opts.box_spacing)
+                        + (x * 2 * render_opts.grid_line_width);
+
+                    let y_cell = render_opts.grid_top_border
+                        + (y * render_opts.letter_cell_height)
+                        + (y * render_opts.box_spacing)
+                        + (y * 2 * render_opts.grid_line_width);
+
+                    let mut colour = if style.shake_row == Some(guess_idx) {
+                        render_opts.shake_colour
+                    } else if cursor == Some((guess_idx, x)) {
+                        render_opts.cursor_colour
+                    } else {
+                        render_opts.grid_colour
+                    };
+
+                    if let (Some(_), Some(lr)) = (letter.0, &letter.1) {
+                        // if there is a result provided then check that we might want to change
+                        // the cell background colour
+                        colour = render_opts.background_colour(lr).unwrap_or(colour);
+                    }
+
+                    if style.dimmed {
+                        colour = render_opts.dimmed_colour;
+                    }
+
+                    let cell = &Rectangle {
+                        x: x_cell as f64,
+                        y: y_cell as f64,
+                        width: render_opts.letter_cell_width as f64,
+                        height: render_opts.letter_cell_height as f64,
+                        color: colour,
+                    };
+
+                    ctx.draw(cell);
+
+                    ctx.print(
+                        (x_cell + (render_opts.letter_cell_width / 2) - 1) as f64,
+                        (y_cell + (render_opts.letter_cell_height / 2) + 1) as f64,
+                        String::from(letter.0.unwrap_or(' ')),
+                    );
+                }
+
+                // Show how many answers were still possible after this guess
+                let remaining = remaining_candidates
+                    .get(guess_idx as usize)
+                    .filter(|_| style.show_remaining);
+
+                if let Some(remaining) = remaining {
+                    let x_count = render_opts.grid_left_border
+                        + game_options.word_length
+                            * (render_opts.letter_cell_width
+                                + render_opts.box_spacing
+                                + 2 * render_opts.grid_line_width);
+
+                    let y_count = render_opts.grid_top_border
+                        + (y * render_opts.letter_cell_height)
+                        + (y * render_opts.box_spacing)
+                        + (y * 2 * render_opts.grid_line_width)
+                        + (render_opts.letter_cell_height / 2)
+                        + 1;
+
+                    ctx.print(
+                        x_count as f64,
+                        y_count as f64,
+                        Line::from(format!("{} left", remaining).dark_gray()),
+                    );
+                }
+            }
+        });
+
+    frame.render_widget(canvas, area);
+    Some(render_opts)
+}
+
+/// Height of the on-screen keyboard, one line per row of keys plus a line of spacing
+const KEYBOARD_HEIGHT: u16 = 4;
+
+/// Draws the on-screen keyboard showing the best-known state of every letter
+///
+/// # Arguments
+/// * `frame` - The frame to draw on
+/// * `area` - The area to draw the keyboard in
+/// * `game_options` - The game options, used to pick the keyboard layout
+/// * `game_data` - The game data
+/// * `render_opts` - The render options providing the key colours
+fn draw_keyboard(
+    frame: &mut Frame,
+    area: Rect,
+    game_options: &GameOptions,
+    game_data: &GameData,
+    render_opts: &RenderOpts,
+) {
+    let letter_states = game_data.letter_states();
+
+    let mut lines = vec![Line::from("")];
+
+    for row in game_options.dictionary.keyboard.rows() {
+        let keys: Vec<Span> = row
+            .chars()
+            .map(|key| {
+                let text = format!(" {} ", key);
+
+                match letter_states.get(&key) {
+                    Some(LetterResult::Absent) => Span::styled(
+                        text,
+                        Style::new().bg(render_opts.absent_key_colour).fg(Color::Gray),
+                    ),
+                    Some(result) => match render_opts.background_colour(result) {
+                        Some(colour) => Span::styled(text, Style::new().bg(colour).fg(Color::Black)),
+                        None => Span::raw(text),
+                    },
+                    None => Span::raw(text),
+                }
+            })
+            .flat_map(|key| [key, Span::raw(" ")])
+            .collect();
+
+        lines.push(Line::from(keys).centered());
+    }
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
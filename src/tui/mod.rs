@@ -0,0 +1,725 @@
+//! Terminal interface for the game, built on ratatui
+//!
+//! The `Application` holds the state of every screen, and `main_loop` draws the
+//! current screen and handles events until the player quits.
+
+pub mod event;
+pub mod game_screen;
+pub mod options_screen;
+pub mod stats_screen;
+
+use crate::config::{Config, ConfigError};
+use crate::game::{GameData, GameError, GameOptions, GameState};
+use crate::multi::MultiGame;
+use crate::options::{OptionData, OptionsError};
+use crate::share;
+use crate::solver;
+use crate::stats::{GameMode, Stats, StatsError};
+use crate::timed::SpeedRun;
+use crate::tui::event::{AppEvent, EventHandler};
+use crate::tui::game_screen::{Toast, HINT_DURATION, NOTICE_DURATION};
+use crate::tui::options_screen::{draw_options};
+use crate::tui::stats_screen::draw_stats;
+use ratatui::backend::Backend;
+use ratatui::crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::Terminal;
+use std::error;
+use std::error::Error;
+use std::fmt::{Debug};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WordleError {
+    #[error("No active game")]
+    NoActiveGame,
+    #[error("Rendering error: {0}")]
+    RenderingError(Box<dyn Error>),
+    #[error("No active options state")]
+    NoActiveOptions,
+    #[error("Stats error: {0}")]
+    StatsError(#[from] StatsError),
+    #[error("Config error: {0}")]
+    ConfigError(#[from] ConfigError),
+    #[error("Options error: {0}")]
+    OptionsError(#[from] OptionsError),
+    #[error("{0}")]
+    GameError(#[from] GameError),
+}
+
+/// Represents the current screen being displayed in the application
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenMode {
+    /// Main game screen where the player guesses words
+    Game,
+    /// Options screen for configuring game settings
+    Options,
+    /// Statistics screen showing the results of previous games
+    Stats,
+    /// Exit the application
+    Quit,
+}
+
+/// Main application state container
+#[derive(Debug)]
+pub struct Application {
+    /// Current game configuration options
+    pub game_options: GameOptions,
+    /// State for the options screen, if active
+    pub options_state: Option<OptionData>,
+    /// State for the current game, if active
+    pub game_state: Option<GameData>,
+    /// State for the current multi-board game, if one is being played instead
+    pub multi_state: Option<MultiGame>,
+    /// Current screen being displayed
+    pub app_state: ScreenMode,
+    /// Statistics for every finished game
+    pub stats: Stats,
+    /// Location the statistics are saved to, if they should be persisted
+    pub stats_path: Option<PathBuf>,
+    /// Name and word length of the dictionary shown on the stats screen
+    pub stats_dictionary: (String, u8),
+    /// Mode of the games shown on the stats screen
+    pub stats_mode: GameMode,
+    /// Shareable result of the most recently finished game
+    pub last_share: Option<String>,
+    /// Location the applied options are saved to, if they should be persisted
+    pub config_path: Option<PathBuf>,
+    /// Message currently shown on the game screen
+    pub toast: Option<Toast>,
+    /// Source of input and timer events
+    pub events: EventHandler,
+    /// The speed run being played, if speed runs are enabled
+    pub speed_run: Option<SpeedRun>,
+}
+
+impl Application {
+    /// Creates a new application with the given game options
+    pub fn new(game_options: GameOptions) -> Self {
+        let stats_dictionary = (
+            game_options.dictionary.name.clone(),
+            game_options.dictionary.length,
+        );
+
+        Self {
+            game_options,
+            options_state: None,
+            game_state: None,
+            multi_state: None,
+            app_state: ScreenMode::Game,
+            stats: Stats::default(),
+            stats_path: None,
+            stats_dictionary,
+            stats_mode: GameMode::Classic,
+            last_share: None,
+            config_path: None,
+            toast: None,
+            events: EventHandler::default(),
+            speed_run: None,
+        }
+    }
+
+    /// Loads the statistics from the given file and saves future results to it
+    ///
+    /// If the file cannot be read the statistics start empty, so a corrupt file
+    /// never prevents the game from starting.
+    pub fn load_stats(&mut self, path: Option<PathBuf>) {
+        if let Some(path) = &path {
+            self.stats = Stats::load(path).unwrap_or_else(|e| {
+                eprintln!("Failed to load stats, starting fresh: {}", e);
+                Stats::default()
+            });
+        }
+
+        self.stats_path = path;
+    }
+
+    /// Records the result of the current game and saves the statistics
+    ///
+    /// A multi-board game is recorded as a single game. Winning a timed game adds its
+    /// time to the leaderboard. In a speed run a win moves straight on to the next
+    /// word instead, and the time of the whole run is added once every word has been
+    /// solved.
+    ///
+    /// Failing to save the statistics does not stop the next word of a speed run, so it
+    /// is shown to the player as a warning rather than returned.
+    pub fn record_game(&mut self) -> Result<(), WordleError> {
+        let now = Instant::now();
+
+        let (won, elapsed) = if let Some(multi_state) = self.multi_state.as_ref() {
+            self.stats.record_multi(multi_state)?;
+            self.last_share = share::multi_share_text(multi_state);
+            (multi_state.game_state == GameState::Won, multi_state.elapsed(now))
+        } else {
+            let game_state = self.game_state.as_ref().ok_or(WordleError::NoActiveGame)?;
+            self.stats.record(game_state)?;
+            self.last_share = share::share_text(game_state);
+            (game_state.game_state == GameState::Won, game_state.elapsed(now))
+        };
+
+        let dictionary = Arc::clone(&self.game_options.dictionary);
+        let mut next_word = false;
+
+        match self.speed_run.as_mut() {
+            Some(run) if !won => run.record_loss(now),
+            Some(run) => {
+                if run.record_win(now) {
+                    self.stats.record_time(&dictionary.name, dictionary.length, run.target, run.elapsed(now));
+                } else {
+                    next_word = true;
+                }
+            }
+            None if won && self.game_options.is_timed() => {
+                self.stats.record_time(&dictionary.name, dictionary.length, 1, elapsed);
+            }
+            None => {}
+        }
+
+        if next_word {
+            self.start_game();
+        }
+
+        if let Some(path) = &self.stats_path {
+            if let Err(e) = self.stats.save(path) {
+                let text = format!("Stats not saved: {}", e);
+                self.show_notice(text);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Starts a new game with the current game options
+    ///
+    /// If speed runs are enabled this also starts a new run. If today's daily puzzle
+    /// has already been played the player is told, as the replay will not be recorded.
+    pub fn new_game(&mut self) {
+        self.speed_run = self
+            .game_options
+            .speed_run
+            .map(|target| SpeedRun::new(target, Instant::now()));
+
+        self.start_game();
+
+        let dictionary = &self.game_options.dictionary;
+        let replay = self
+            .game_state
+            .as_ref()
+            .and_then(|game| game.daily)
+            .is_some_and(|date| self.stats.played_daily(&dictionary.name, dictionary.length, date));
+
+        if replay {
+            let text = String::from("Daily already played, this game will not be recorded");
+            self.show_notice(text);
+        }
+    }
+
+    /// Starts the next game with the current game options, keeping any speed run going
+    ///
+    /// A multi-board game is started instead if more than one board is selected. If a
+    /// seed has been set it is advanced after each game, so a seeded session plays the
+    /// same sequence of answers every time. Daily mode is ignored during a speed run,
+    /// as every word of the run would be the same daily word.
+    fn start_game(&mut self) {
+        let mut options = self.game_options.clone();
+        options.daily &= self.speed_run.is_none();
+
+        if options.boards > 1 {
+            self.game_state = None;
+            self.multi_state = Some(MultiGame::new(&options));
+        } else {
+            self.game_state = Some(GameData::new(&options));
+            self.multi_state = None;
+        }
+
+        self.toast = None;
+
+        if let Some(seed) = self.game_options.seed.as_mut() {
+            *seed = seed.wrapping_add(1);
+        }
+    }
+
+    /// Updates time-driven state, called every tick
+    ///
+    /// A game that runs out of time is lost and recorded here, as no key press
+    /// finishes it.
+    pub fn on_tick(&mut self) -> Result<(), WordleError> {
+        if self.toast.as_ref().is_some_and(Toast::is_expired) {
+            self.toast = None;
+        }
+
+        let now = Instant::now();
+
+        let finished = if let Some(multi_state) = self.multi_state.as_mut() {
+            let was_active = multi_state.game_state == GameState::Active;
+            was_active && multi_state.check_time(now)? != GameState::Active
+        } else if let Some(game_state) = self.game_state.as_mut() {
+            let was_active = game_state.game_state == GameState::Active;
+            was_active && game_state.check_time(now)? != GameState::Active
+        } else {
+            false
+        };
+
+        if finished {
+            self.record_game()?;
+        }
+
+        Ok(())
+    }
+
+    /// Shows an error to the player on the current screen
+    ///
+    /// Errors caused by input after the game has finished are not shown, and
+    /// rejected guesses also highlight the active row.
+    pub fn show_error(&mut self, error: &(dyn Error + 'static)) {
+        let game_error = error.downcast_ref::<GameError>();
+
+        if matches!(game_error, Some(GameError::NoActiveGame)) {
+            return;
+        }
+
+        let shake = game_error.is_some_and(GameError::is_rejected_guess);
+        self.toast = Some(Toast::new(error.to_string(), shake));
+    }
+
+    /// Shows a notice to the player on the current screen, for longer than an error
+    /// so it can be read
+    pub fn show_notice(&mut self, text: String) {
+        self.toast = Some(Toast::with_duration(text, false, NOTICE_DURATION));
+    }
+
+    /// Shows a hint for the next guess and records that a hint was used
+    pub fn hint(&mut self) -> Result<(), Box<dyn Error>> {
+        let game_state = self.game_state.as_mut().ok_or(WordleError::NoActiveGame)?;
+        if game_state.game_state != GameState::Active {
+            return Err(Box::new(GameError::NoActiveGame));
+        }
+
+        let hint = solver::hint(game_state)?;
+        game_state.hints_used += 1;
+
+        let text = match hint.suggestion {
+            Some(suggestion) => format!("{} possible words, try {}", hint.remaining, suggestion),
+            None => String::from("No possible words left"),
+        };
+
+        self.toast = Some(Toast::with_duration(text, false, HINT_DURATION));
+        Ok(())
+    }
+
+    /// Switches to the options screen, showing the current game options
+    pub fn options(&mut self) {
+        self.options_state = Some(OptionData::new(&self.game_options));
+        self.app_state = ScreenMode::Options;
+        self.toast = None;
+    }
+
+    /// Applies the options screen to the game options, starts a new game with them and
+    /// saves them for future sessions
+    ///
+    /// Failing to save the options does not stop them being used, so it is shown to
+    /// the player as a warning rather than returned.
+    pub fn apply_options(&mut self) -> Result<(), WordleError> {
+        let options_state = self.options_state.as_ref().ok_or(WordleError::NoActiveOptions)?;
+        options_state.apply(&mut self.game_options)?;
+
+        self.new_game();
+        self.app_state = ScreenMode::Game;
+
+        if let Some(path) = &self.config_path {
+            if let Err(e) = Config::from_options(&self.game_options).save(path) {
+                let text = format!("Options not saved: {}", e);
+                self.show_notice(text);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Switches to the stats screen, showing the current dictionary and mode
+    pub fn stats(&mut self) {
+        self.stats_dictionary = (
+            self.game_options.dictionary.name.clone(),
+            self.game_options.dictionary.length,
+        );
+        self.stats_mode = GameMode::from_options(&self.game_options);
+        self.app_state = ScreenMode::Stats;
+        self.toast = None;
+    }
+
+    /// Selects the next (or previous) dictionary shown on the stats screen
+    pub fn cycle_stats_dictionary(&mut self, forward: bool) {
+        let mut dictionaries = self.stats.dictionaries();
+        if !dictionaries.contains(&self.stats_dictionary) {
+            dictionaries.push(self.stats_dictionary.clone());
+        }
+
+        let len = dictionaries.len();
+        if let Some(idx) = dictionaries.iter().position(|d| *d == self.stats_dictionary) {
+            let next = if forward { (idx + 1) % len } else { (idx + len - 1) % len };
+            self.stats_dictionary = dictionaries[next].clone();
+        }
+    }
+
+    /// Selects the next (or previous) mode shown on the stats screen
+    ///
+    /// Only the modes played with the dictionary being shown are offered, along with
+    /// the mode already shown.
+    pub fn cycle_stats_mode(&mut self, forward: bool) {
+        let mut modes = self.stats.modes(&self.stats_dictionary.0, self.stats_dictionary.1);
+        if !modes.contains(&self.stats_mode) {
+            modes.push(self.stats_mode);
+        }
+
+        let len = modes.len();
+        if let Some(idx) = modes.iter().position(|m| *m == self.stats_mode) {
+            let next = if forward { (idx + 1) % len } else { (idx + len - 1) % len };
+            self.stats_mode = modes[next];
+        }
+    }
+
+    /// Sets the application to quit
+    pub fn quit(&mut self) {
+        self.app_state = ScreenMode::Quit;
+    }
+}
+
+/// Main application loop that handles screen transitions and error recovery
+///
+/// This function runs until the application is set to quit. Each step redraws the
+/// current screen and then handles a single event, so the screen is redrawn at least
+/// once per tick even when no keys are pressed. It handles errors by logging them and
+/// continuing execution to prevent the game from crashing.
+pub fn main_loop<B: Backend>(
+    app: &mut Application,
+    mut terminal: Terminal<B>,
+) -> Result<(), Box<dyn error::Error>> {
+    while app.app_state != ScreenMode::Quit {
+        step(app, &mut terminal);
+    }
+
+    Ok(())
+}
+
+/// Processes a single frame of the current screen
+///
+/// Errors are handled here rather than returned, so that a failed step never stops
+/// the application.
+pub fn step<B: Backend>(app: &mut Application, terminal: &mut Terminal<B>) {
+    match app.app_state {
+        ScreenMode::Game => {
+            let result = if app.multi_state.is_some() {
+                step_multi_game(app, terminal)
+            } else {
+                step_game(app, terminal)
+            };
+
+            // Show errors to the player but continue execution to prevent game from crashing
+            if let Err(e) = result {
+                app.show_error(e.as_ref());
+            }
+        }
+        ScreenMode::Options => {
+            // Show errors on the options screen, so the player can pick something else
+            if let Err(e) = step_options(app, terminal) {
+                app.show_error(e.as_ref());
+            }
+        }
+        ScreenMode::Stats => {
+            // Show errors on the stats screen but continue execution
+            if let Err(e) = step_stats(app, terminal) {
+                app.show_error(e.as_ref());
+            }
+        }
+        ScreenMode::Quit => {}
+    }
+}
+
+/// Processes a single frame of the game screen
+///
+/// This function:
+/// 1. Gets the active game state
+/// 2. Renders the game screen
+/// 3. Waits for the next event and processes keyboard input for game actions
+///
+/// Returns an error if there's no active game or if rendering fails.
+pub fn step_game<B: Backend>(app: &mut Application, terminal: &mut Terminal<B>) -> Result<(), Box<dyn error::Error>> {
+    // Use the ? operator with Option to handle the None case more idiomatically
+    let game_state = app.game_state.as_mut().ok_or(WordleError::NoActiveGame)?;
+
+    // Draw the game state
+    terminal
+        .draw(|frame| {
+            game_screen::draw_game(frame, &app.game_options, game_state, app.speed_run.as_ref(), app.toast.as_ref())
+        })
+        .map_err(|e| WordleError::RenderingError(Box::new(e)))?;
+
+    // Handle the next event, ticks and resizes only need the screen to be redrawn
+    let key = match app.events.next_event()? {
+        AppEvent::Key(key) => key,
+        AppEvent::Tick => {
+            app.on_tick()?;
+            return Ok(());
+        }
+        AppEvent::Resize(_, _) => return Ok(()),
+    };
+
+    // Handle keyboard input
+    if key.kind == KeyEventKind::Press {
+        match key.code {
+            KeyCode::Enter => {
+                let state = game_state.submit_word()?;
+
+                // Record the result as soon as the game finishes
+                if state != GameState::Active {
+                    app.record_game()?;
+                }
+            }
+            KeyCode::Char(to_insert) => {
+                if key.modifiers == KeyModifiers::CONTROL {
+                    // Handle control key combinations
+                    match to_insert.to_ascii_uppercase() {
+                        'N' => app.new_game(),
+                        'O' => app.options(),
+                        'S' => app.stats(),
+                        'Y' => {
+                            if let Some(text) = share::share_text(game_state) {
+                                share::copy_to_clipboard(&text)?;
+                            }
+                        }
+                        // Some terminals send Backspace as Ctrl-H
+                        'H' => game_state.delete_letter()?,
+                        'U' => game_state.clear_guess()?,
+                        'Q' => app.quit(),
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+
+                // Add letter if it's alphabetic
+                if to_insert.is_alphabetic() {
+                    game_state.add_letter(to_insert)?;
+                }
+            }
+            KeyCode::Backspace if key.modifiers == KeyModifiers::CONTROL => {
+                game_state.clear_guess()?;
+            }
+            KeyCode::Backspace => {
+                game_state.delete_letter()?;
+            }
+            KeyCode::Delete => {
+                game_state.clear_letter()?;
+            }
+            KeyCode::Left => {
+                game_state.move_cursor_left()?;
+            }
+            KeyCode::Right => {
+                game_state.move_cursor_right()?;
+            }
+            KeyCode::Tab => {
+                app.hint()?;
+            }
+            KeyCode::Esc => {
+                app.quit();
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes a single frame of the multi-board game screen
+///
+/// This works like `step_game`, except every guess is typed into every unsolved
+/// board at once. Hints and sharing are not available for multi-board games.
+///
+/// Returns an error if there's no active multi-board game or if rendering fails.
+pub fn step_multi_game<B: Backend>(app: &mut Application, terminal: &mut Terminal<B>) -> Result<(), Box<dyn error::Error>> {
+    let multi_state = app.multi_state.as_mut().ok_or(WordleError::NoActiveGame)?;
+
+    // Draw the boards
+    terminal
+        .draw(|frame| {
+            game_screen::draw_multi_game(frame, multi_state, app.speed_run.as_ref(), app.toast.as_ref())
+        })
+        .map_err(|e| WordleError::RenderingError(Box::new(e)))?;
+
+    // Handle the next event, ticks and resizes only need the screen to be redrawn
+    let key = match app.events.next_event()? {
+        AppEvent::Key(key) => key,
+        AppEvent::Tick => {
+            app.on_tick()?;
+            return Ok(());
+        }
+        AppEvent::Resize(_, _) => return Ok(()),
+    };
+
+    // Handle keyboard input
+    if key.kind == KeyEventKind::Press {
+        match key.code {
+            KeyCode::Enter => {
+                let state = multi_state.submit_word()?;
+
+                // Record the result as soon as the game finishes
+                if state != GameState::Active {
+                    app.record_game()?;
+                }
+            }
+            KeyCode::Char(to_insert) => {
+                if key.modifiers == KeyModifiers::CONTROL {
+                    // Handle control key combinations
+                    match to_insert.to_ascii_uppercase() {
+                        'N' => app.new_game(),
+                        'O' => app.options(),
+                        'S' => app.stats(),
+                        'Q' => app.quit(),
+                        // Some terminals send Backspace as Ctrl-H
+                        'H' => multi_state.delete_letter()?,
+                        'U' => multi_state.clear_guess()?,
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+
+                // Add letter if it's alphabetic
+                if to_insert.is_alphabetic() {
+                    multi_state.add_letter(to_insert)?;
+                }
+            }
+            KeyCode::Backspace if key.modifiers == KeyModifiers::CONTROL => {
+                multi_state.clear_guess()?;
+            }
+            KeyCode::Backspace => {
+                multi_state.delete_letter()?;
+            }
+            KeyCode::Delete => {
+                multi_state.clear_letter()?;
+            }
+            KeyCode::Left => {
+                multi_state.move_cursor_left()?;
+            }
+            KeyCode::Right => {
+                multi_state.move_cursor_right()?;
+            }
+            KeyCode::Esc => {
+                app.quit();
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes a single frame of the options screen
+///
+/// This function:
+/// 1. Gets the active options state
+/// 2. Renders the options screen
+/// 3. Waits for the next event and processes keyboard input for options navigation and selection
+///
+/// Returns an error if there's no active options state or if rendering fails.
+pub fn step_options<B: Backend>(app: &mut Application, terminal: &mut Terminal<B>) -> Result<(), Box<dyn Error>> {
+    // Use the ? operator with Option to handle the None case more idiomatically
+    let options_state = app.options_state.as_mut().ok_or(WordleError::NoActiveOptions)?;
+
+    // Draw the options screen
+    terminal
+        .draw(|frame| {
+            draw_options(frame, options_state, app.toast.as_ref());
+        })
+        .map_err(|e| WordleError::RenderingError(Box::new(e)))?;
+
+    // Handle the next event, ticks and resizes only need the screen to be redrawn
+    let key = match app.events.next_event()? {
+        AppEvent::Key(key) => key,
+        AppEvent::Tick => {
+            app.on_tick()?;
+            return Ok(());
+        }
+        AppEvent::Resize(_, _) => return Ok(()),
+    };
+
+    // Handle keyboard input
+    if key.kind == KeyEventKind::Press {
+        match key.code {
+            // Apply options and return to game
+            KeyCode::Enter => {
+                app.apply_options()?;
+                return Ok(());
+            }
+            // Cancel and return to game
+            KeyCode::Esc => {
+                app.new_game();
+                app.app_state = ScreenMode::Game;
+                return Ok(());
+            }
+            // Navigation keys
+            KeyCode::Up => options_state.previous(),
+            KeyCode::Down => options_state.next(),
+            KeyCode::Left => options_state.decrement_tries(),
+            KeyCode::Right => options_state.increment_tries(),
+            KeyCode::Char('h') | KeyCode::Char('H') => options_state.toggle_hard_mode(),
+            KeyCode::Char('d') | KeyCode::Char('D') => options_state.toggle_daily(),
+            KeyCode::Char('b') | KeyCode::Char('B') => options_state.cycle_boards(),
+            KeyCode::Char('a') | KeyCode::Char('A') => options_state.toggle_absurdle(),
+            KeyCode::Char('t') | KeyCode::Char('T') => options_state.cycle_time_limit(),
+            KeyCode::Char('g') | KeyCode::Char('G') => options_state.cycle_guess_time_limit(),
+            KeyCode::Char('r') | KeyCode::Char('R') => options_state.cycle_speed_run(),
+            KeyCode::Char('l') | KeyCode::Char('L') => options_state.cycle_difficulty(),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes a single frame of the stats screen
+///
+/// This function:
+/// 1. Renders the stats screen
+/// 2. Waits for the next event and processes keyboard input for choosing a dictionary
+///    and mode, and leaving the screen
+///
+/// Returns an error if rendering fails.
+pub fn step_stats<B: Backend>(app: &mut Application, terminal: &mut Terminal<B>) -> Result<(), Box<dyn Error>> {
+    // Draw the stats screen
+    terminal
+        .draw(|frame| {
+            draw_stats(frame, &app.stats, &app.stats_dictionary, app.stats_mode, app.toast.as_ref());
+        })
+        .map_err(|e| WordleError::RenderingError(Box::new(e)))?;
+
+    // Handle the next event, ticks and resizes only need the screen to be redrawn
+    let key = match app.events.next_event()? {
+        AppEvent::Key(key) => key,
+        AppEvent::Tick => {
+            app.on_tick()?;
+            return Ok(());
+        }
+        AppEvent::Resize(_, _) => return Ok(()),
+    };
+
+    // Handle keyboard input
+    if key.kind == KeyEventKind::Press {
+        match key.code {
+            // Return to the game, leaving it as it was
+            KeyCode::Enter | KeyCode::Esc => {
+                app.app_state = ScreenMode::Game;
+            }
+            // Navigation keys
+            KeyCode::Up => app.cycle_stats_dictionary(false),
+            KeyCode::Down => app.cycle_stats_dictionary(true),
+            KeyCode::Left => app.cycle_stats_mode(false),
+            KeyCode::Right => app.cycle_stats_mode(true),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
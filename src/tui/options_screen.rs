@@ -0,0 +1,104 @@
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+use tui_big_text::{BigText, PixelSize};
+use crate::options::OptionData;
+use crate::tui::game_screen::Toast;
+
+/// Width of the list of settings, wide enough for the longest label, key and value
+const SETTINGS_WIDTH: u16 = 36;
+
+/// Draws the options screen
+///
+/// Only the dictionary is drawn in large text, so every setting and the controls
+/// bar still fit on a small terminal.
+///
+/// # Arguments
+/// * `frame` - The frame to draw on
+/// * `options_data` - The options data to display
+/// * `toast` - A message to show the player, such as why the options were not applied
+pub fn draw_options(frame: &mut Frame, options_data: &OptionData, toast: Option<&Toast>) {
+    let on_off = |value: bool| String::from(if value { "On" } else { "Off" });
+    let seconds = |limit: Option<u64>| match limit {
+        Some(secs) => format!("{}s", secs),
+        None => String::from("Off"),
+    };
+
+    // The difficulty only applies to dictionaries that give word frequencies
+    let difficulty = match options_data.difficulty_available() {
+        true => options_data.difficulty().to_string(),
+        false => String::from("Unavailable"),
+    };
+
+    // Each setting with the key that changes it
+    let settings = [
+        ("Guesses", "Left/Right", options_data.max_tries().to_string()),
+        ("Difficulty", "L", difficulty),
+        ("Hard Mode", "H", on_off(options_data.hard_mode())),
+        ("Daily", "D", on_off(options_data.daily())),
+        ("Boards", "B", options_data.boards().to_string()),
+        ("Absurdle", "A", on_off(options_data.absurdle())),
+        ("Timer", "T", seconds(options_data.time_limit())),
+        ("Per Guess", "G", seconds(options_data.guess_time_limit())),
+        ("Speed Run", "R", match options_data.speed_run() {
+            Some(words) => format!("{} Words", words),
+            None => String::from("Off"),
+        }),
+    ];
+
+    // Split the screen into sections for different UI elements
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Fill(1),                                // Top spacing
+            Constraint::Length(4),                              // Dictionary display
+            Constraint::Length(1),                              // Spacing
+            Constraint::Length(settings.len() as u16),          // Settings
+            Constraint::Fill(1),                                // Bottom spacing
+            Constraint::Length(1),                              // Message line
+            Constraint::Length(1)                               // Controls bar
+        ])
+        .split(frame.area());
+
+    // Render the dictionary selection
+    frame.render_widget(
+        BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from(format!(
+                "{} - {} Letters",
+                options_data.dictionary_name(),
+                options_data.dictionary_length()
+            ))])
+            .centered()
+            .build(),
+        layout[1]
+    );
+
+    // Render the settings in a centred column so their values line up
+    let column = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Fill(1), Constraint::Length(SETTINGS_WIDTH), Constraint::Fill(1)])
+        .split(layout[3]);
+
+    let lines: Vec<Line> = settings
+        .iter()
+        .map(|(label, key, value)| Line::from(format!("{:<12}{:<12}{}", label, key, value)))
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), column[1]);
+
+    // Render any message, such as why the options could not be applied
+    if let Some(toast) = toast.filter(|t| !t.is_expired()) {
+        frame.render_widget(Paragraph::new(Line::from(toast.text.as_str().red().bold()).centered()), layout[5]);
+    }
+
+    // Render the controls bar
+    let controls_bar = Block::default()
+        .title(Line::from(
+            "Select: Enter, Cancel: ESC, Dictionary: Up/Down, Quit: CTRL-Q"
+        ).left_aligned());
+
+    frame.render_widget(controls_bar, layout[6]);
+}
@@ -0,0 +1,142 @@
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Paragraph};
+use ratatui::Frame;
+use tui_big_text::{BigText, PixelSize};
+use crate::stats::{GameMode, Stats};
+use crate::timed::format_clock;
+use crate::tui::game_screen::Toast;
+
+/// Most times shown on the leaderboard for each number of words
+const LEADERBOARD_SIZE: usize = 5;
+/// Width of the leaderboard panel, wide enough for a line per time plus its border
+const LEADERBOARD_WIDTH: u16 = 33;
+
+/// Draws the statistics screen for a single dictionary and mode
+///
+/// # Arguments
+/// * `frame` - The frame to draw on
+/// * `stats` - The player statistics
+/// * `dictionary` - The name and word length of the dictionary to display
+/// * `mode` - The mode of the games to display
+/// * `toast` - A message to show the player, if any
+pub fn draw_stats(frame: &mut Frame, stats: &Stats, dictionary: &(String, u8), mode: GameMode, toast: Option<&Toast>) {
+    let summary = stats.summary(&dictionary.0, dictionary.1, mode);
+
+    // Split the screen into sections for different UI elements
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Max(5),        // Dictionary display
+            Constraint::Max(5),        // Played and win percentage display
+            Constraint::Max(5),        // Streak display
+            Constraint::Fill(1),       // Guess distribution
+            Constraint::Length(1),     // Message line
+            Constraint::Max(5)         // Controls bar
+        ])
+        .split(frame.area());
+
+    // Render the dictionary being displayed
+    frame.render_widget(
+        BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from(format!(
+                "{} - {} {}",
+                mode.name(),
+                dictionary.0,
+                dictionary.1
+            ))])
+            .centered()
+            .build(),
+        layout[0]
+    );
+
+    // Render the games played and win percentage
+    frame.render_widget(
+        BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from(format!(
+                "Played: {}  Win %: {}  Assisted: {}",
+                summary.played,
+                summary.win_percentage(),
+                summary.assisted_wins
+            ))])
+            .centered()
+            .build(),
+        layout[1]
+    );
+
+    // Render the current and maximum streaks
+    frame.render_widget(
+        BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from(format!(
+                "Streak: {}  Max: {}",
+                summary.current_streak,
+                summary.max_streak
+            ))])
+            .centered()
+            .build(),
+        layout[2]
+    );
+
+    // Render the guess distribution as a horizontal bar chart
+    let bars: Vec<Bar> = summary
+        .distribution
+        .iter()
+        .enumerate()
+        .map(|(i, count)| {
+            Bar::default()
+                .label(Line::from(format!("{}", i + 1)))
+                .value(*count as u64)
+                .style(Style::new().fg(Color::LightGreen))
+        })
+        .collect();
+
+    let distribution = BarChart::default()
+        .block(Block::bordered().title(Line::from("Guess Distribution").centered()))
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .data(BarGroup::default().bars(&bars));
+
+    // Show the leaderboard beside the distribution if any times have been set
+    let best_times = stats.best_times(&dictionary.0, dictionary.1, LEADERBOARD_SIZE);
+
+    if best_times.is_empty() {
+        frame.render_widget(distribution, layout[3]);
+    } else {
+        let panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Fill(1), Constraint::Length(LEADERBOARD_WIDTH)])
+            .split(layout[3]);
+
+        let lines: Vec<Line> = best_times
+            .iter()
+            .map(|record| {
+                let words = if record.words == 1 { String::from("1 word") } else { format!("{} words", record.words) };
+                Line::from(format!("{:<9} {:>6}  {}", words, format_clock(record.time()), record.date.format("%Y-%m-%d")))
+            })
+            .collect();
+
+        let leaderboard = Paragraph::new(lines)
+            .block(Block::bordered().title(Line::from("Best Times").centered()));
+
+        frame.render_widget(distribution, panels[0]);
+        frame.render_widget(leaderboard, panels[1]);
+    }
+
+    // Render any message, such as why the screen could not be updated
+    if let Some(toast) = toast.filter(|t| !t.is_expired()) {
+        frame.render_widget(Paragraph::new(Line::from(toast.text.as_str().red().bold()).centered()), layout[4]);
+    }
+
+    // Render the controls bar
+    let controls_bar = Block::default()
+        .title(Line::from(
+            "Back: Enter | ESC, Dictionary: Up/Down, Mode: Left/Right"
+        ).left_aligned());
+
+    frame.render_widget(controls_bar, layout[5]);
+}
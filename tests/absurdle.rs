@@ -0,0 +1,169 @@
+//! Tests for Absurdle mode, where the answer is chosen as the game is played
+
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tui_wordle::config::Config;
+use tui_wordle::game::{score_guess, GameData, GameError, GameOptions, GameState, LetterResult};
+use tui_wordle::manifest::{Manifest, MANIFEST_FILE};
+use tui_wordle::options::{OptionData, OptionsError};
+use tui_wordle::solver::is_consistent;
+use tui_wordle::stats::{GameMode, GameOutcome, GameRecord, Stats};
+
+fn absurdle_game() -> GameData {
+    let options = GameOptions {
+        absurdle: true,
+        ..GameOptions::default()
+    };
+
+    GameData::new(&options)
+}
+
+fn guess(game: &mut GameData, word: &str) -> Result<GameState, GameError> {
+    for c in word.chars() {
+        game.add_letter(c)?;
+    }
+    game.submit_word()
+}
+
+#[test]
+fn answer_is_not_chosen_up_front() {
+    let game = absurdle_game();
+
+    assert!(game.is_answer_pending());
+    assert!(game.answer.is_empty());
+}
+
+#[test]
+fn guess_keeps_the_largest_group_of_answers() {
+    let mut game = absurdle_game();
+    let words = game.options().dictionary.words().unwrap();
+
+    guess(&mut game, "crane").unwrap();
+
+    let guess_chars: Vec<char> = "CRANE".chars().collect();
+    let mut buckets: HashMap<Vec<LetterResult>, usize> = HashMap::new();
+    for word in &words {
+        let answer: Vec<char> = word.chars().collect();
+        *buckets.entry(score_guess(&answer, &guess_chars)).or_insert(0) += 1;
+    }
+
+    let largest = buckets.values().max().copied().unwrap();
+    assert_eq!(game.remaining_candidates()[0], largest);
+    assert_eq!(game.game_state, GameState::Active);
+}
+
+#[test]
+fn losing_reveals_an_answer_consistent_with_every_result() {
+    let mut game = absurdle_game();
+
+    let mut state = GameState::Active;
+    for word in ["crane", "moist", "cloud", "badge", "swift", "plumb"] {
+        state = guess(&mut game, word).unwrap();
+    }
+
+    assert_eq!(state, GameState::Lost);
+    assert!(!game.is_answer_pending());
+
+    let answer: Vec<char> = game.options().dictionary.normalise(&game.answer).chars().collect();
+    assert!(is_consistent(&answer, &game.completed_guesses()));
+}
+
+#[test]
+fn answer_is_fixed_once_only_one_word_fits() {
+    let options = GameOptions {
+        absurdle: true,
+        max_guesses: 10,
+        ..GameOptions::default()
+    };
+    let mut game = GameData::new(&options);
+
+    // Keep guessing a remaining word until the game has to commit to an answer
+    while game.is_answer_pending() && game.game_state == GameState::Active {
+        let words = game.options().dictionary.filter_words(&game.completed_guesses()).unwrap();
+        guess(&mut game, &words[0].to_lowercase()).unwrap();
+    }
+
+    if game.game_state == GameState::Active {
+        let answer = game.answer.to_lowercase();
+        assert_eq!(guess(&mut game, &answer).unwrap(), GameState::Won);
+    }
+    assert_eq!(game.remaining_candidates().last(), Some(&1));
+}
+
+#[test]
+fn absurdle_games_are_summarised_separately() {
+    let record = |absurdle, outcome| GameRecord {
+        dictionary: String::from("Wordle"),
+        length: 5,
+        max_guesses: 6,
+        guesses: 6,
+        outcome,
+        hints: 0,
+        daily: None,
+        absurdle,
+        boards: 1,
+    };
+
+    let stats = Stats {
+        games: vec![
+            record(false, GameOutcome::Won),
+            record(true, GameOutcome::Lost),
+            record(true, GameOutcome::Won),
+        ],
+        ..Stats::default()
+    };
+
+    let summary = stats.summary("Wordle", 5, GameMode::Classic);
+    assert_eq!(summary.played, 1);
+    assert_eq!(summary.won, 1);
+    assert_eq!(summary.current_streak, 1);
+    assert_eq!(summary.distribution[5], 1);
+
+    let summary = stats.summary("Wordle", 5, GameMode::Absurdle);
+    assert_eq!((summary.played, summary.won, summary.max_streak), (2, 1, 1));
+    assert_eq!(stats.modes("Wordle", 5), vec![GameMode::Classic, GameMode::Absurdle]);
+}
+
+#[test]
+fn revealed_answer_keeps_its_accents() {
+    let dir = std::env::temp_dir().join(format!("tui-wordle-absurdle-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("german.txt"), "würze\nhallo\n").unwrap();
+
+    let path = dir.join(MANIFEST_FILE);
+    fs::write(&path, "[[dictionary]]\nname = \"German\"\npath = \"german.txt\"\nlengths = [5]\naccents = \"strip\"\n").unwrap();
+    let dictionary = Manifest::load(&path).unwrap().dictionaries(&path).unwrap().remove(0);
+
+    let options = GameOptions {
+        absurdle: true,
+        dictionary: Arc::new(dictionary),
+        time_limit: Some(Duration::ZERO),
+        ..GameOptions::default()
+    };
+    let mut game = GameData::new(&options);
+
+    assert_eq!(game.check_time(Instant::now()).unwrap(), GameState::Lost);
+    assert_eq!(game.answer, "würze");
+}
+
+#[test]
+fn absurdle_is_only_played_on_one_board() {
+    let mut options = GameOptions::default();
+
+    let mut option_data = OptionData::new(&options);
+    option_data.toggle_absurdle();
+    option_data.cycle_boards();
+    assert!(matches!(option_data.apply(&mut options), Err(OptionsError::AbsurdleBoards)));
+    assert!(!options.absurdle);
+
+    let config = Config {
+        absurdle: true,
+        boards: 4,
+        ..Config::from_options(&options)
+    };
+    config.apply(&mut options).unwrap();
+    assert_eq!(options.boards, 4);
+    assert!(!options.absurdle);
+}
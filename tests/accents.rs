@@ -0,0 +1,82 @@
+//! Tests for accented letters in the French, Dutch and Italian dictionaries
+
+use std::sync::Arc;
+use tui_wordle::dictionary::{get_dictionaries, strip_accent, AccentPolicy, Dictionary};
+use tui_wordle::game::{GameData, GameOptions, GameState, LetterResult};
+
+fn dictionary(name: &str, length: u8) -> Arc<Dictionary> {
+    get_dictionaries()
+        .into_iter()
+        .find(|d| d.name == name && d.length == length)
+        .unwrap()
+}
+
+fn game_with_answer(name: &str, answer: &str) -> GameData {
+    let mut options = GameOptions::default();
+    options.set_dictionary(name, answer.chars().count() as u8).unwrap();
+
+    let mut game = GameData::new(&options);
+    game.answer = answer.to_string();
+    game
+}
+
+fn guess(game: &mut GameData, word: &str) -> GameState {
+    for c in word.chars() {
+        game.add_letter(c).unwrap();
+    }
+    game.submit_word().unwrap()
+}
+
+#[test]
+fn accents_are_stripped_from_letters() {
+    assert_eq!(strip_accent('é'), 'e');
+    assert_eq!(strip_accent('Ç'), 'C');
+    assert_eq!(strip_accent('æ'), 'æ');
+    assert_eq!(AccentPolicy::Strip.normalise("abaissé"), "ABAISSE");
+    assert_eq!(AccentPolicy::Exact.normalise("abaissé"), "ABAISSÉ");
+}
+
+#[test]
+fn letters_with_a_longer_upper_case_are_kept() {
+    assert_eq!(AccentPolicy::Exact.normalise("straße"), "STRAßE");
+    assert_eq!(AccentPolicy::Strip.normalise("straße"), "STRAßE");
+    assert_eq!(AccentPolicy::Exact.normalise_letter('ß'), 'ß');
+}
+
+#[test]
+fn word_length_counts_letters_not_bytes() {
+    for (name, length) in [("French", 5), ("Italian", 6), ("Dutch", 7)] {
+        let words = dictionary(name, length).words().unwrap();
+
+        assert!(!words.is_empty());
+        assert!(words.iter().all(|w| w.chars().count() == length as usize), "{} {}", name, length);
+        assert!(words.iter().all(|w| w.chars().all(|c| c.is_ascii_uppercase())), "{} {}", name, length);
+    }
+}
+
+#[test]
+fn unaccented_guess_matches_accented_answer() {
+    let mut game = game_with_answer("French", "abaissé");
+
+    assert_eq!(guess(&mut game, "abaisse"), GameState::Won);
+    assert_eq!(game.answer, "abaissé");
+}
+
+#[test]
+fn accented_input_is_stripped() {
+    let mut game = game_with_answer("French", "abaissé");
+
+    guess(&mut game, "abaissâ");
+    let result = game.guesses[0].result().unwrap();
+    assert_eq!(game.guesses[0].as_chars().last(), Some(&'A'));
+    assert_eq!(result[6], LetterResult::Absent);
+}
+
+#[test]
+fn accented_words_are_valid_guesses() {
+    let french = dictionary("French", 7);
+
+    assert!(french.is_valid_word("abaisse").unwrap());
+    assert!(french.is_valid_word("ABAISSÉ").unwrap());
+    assert!(!dictionary("Scrabble", 7).is_valid_word("abaisse").unwrap());
+}
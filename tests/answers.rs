@@ -0,0 +1,42 @@
+//! Tests for dictionaries that choose answers from a smaller list than they accept as guesses
+
+use std::sync::Arc;
+use tui_wordle::dictionary::{get_dictionaries, Dictionary, Difficulty};
+
+/// Finds a built-in dictionary by name and word length
+fn dictionary(name: &str, length: u8) -> Arc<Dictionary> {
+    get_dictionaries()
+        .into_iter()
+        .find(|d| d.name == name && d.length == length)
+        .unwrap()
+}
+
+#[test]
+fn wordle_accepts_more_guesses_than_it_has_answers() {
+    let wordle = dictionary("Wordle", 5);
+
+    let answers = wordle.words().unwrap();
+    let guesses = wordle.guess_words().unwrap();
+
+    assert!(guesses.len() > answers.len());
+    assert_eq!(&guesses[..answers.len()], &answers[..]);
+    assert!(!answers.contains(&String::from("AAHED")));
+    assert!(wordle.is_valid_word("aahed").unwrap());
+}
+
+#[test]
+fn scrabble_answers_are_common_words() {
+    let scrabble = dictionary("Scrabble", 5);
+    let wordle = dictionary("Wordle", 5);
+
+    let answers = scrabble.words().unwrap();
+    let common = wordle.words().unwrap();
+
+    assert!(answers.iter().all(|word| common.contains(word)));
+    assert!(scrabble.guess_words().unwrap().len() > answers.len());
+
+    for _ in 0..20 {
+        let word = scrabble.random_word(Difficulty::Hard).unwrap();
+        assert!(common.contains(&word.to_uppercase()));
+    }
+}
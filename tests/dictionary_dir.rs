@@ -0,0 +1,35 @@
+//! Tests for finding word lists on disk and in the binary
+//!
+//! The dictionary directory is shared by the whole process, so everything that
+//! changes it is checked in a single test.
+
+use std::fs;
+use tui_wordle::dictionary::{dictionary_dirs, load_word_list, set_dictionary_dir, DictionaryError};
+
+#[test]
+fn word_lists_are_found_in_the_dictionary_dir_before_the_built_in_lists() {
+    let built_in = load_word_list("wordle.txt").unwrap();
+    assert!(built_in.len() > 100);
+
+    let dir = std::env::temp_dir().join(format!("tui-wordle-dictionaries-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wordle.txt"), "crane\nhyper\n").unwrap();
+
+    set_dictionary_dir(Some(dir.clone()));
+    assert_eq!(dictionary_dirs()[0], dir);
+    assert_eq!(load_word_list("wordle.txt").unwrap(), vec!["crane", "hyper"]);
+
+    match load_word_list("klingon.txt") {
+        Err(e @ DictionaryError::FileNotFound { .. }) => {
+            let message = e.to_string();
+            assert!(message.contains("klingon.txt"));
+            assert!(message.contains(&dir.join("klingon.txt").display().to_string()));
+        }
+        other => panic!("expected a missing file error, got {:?}", other),
+    }
+
+    set_dictionary_dir(None);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(load_word_list("wordle.txt").unwrap(), built_in);
+}
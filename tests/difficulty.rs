@@ -0,0 +1,81 @@
+//! Tests for choosing answers by word frequency and difficulty
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tui_wordle::dictionary::{Dictionary, Difficulty, WordIndex};
+use tui_wordle::game::{GameData, GameOptions};
+use tui_wordle::manifest::{Manifest, MANIFEST_FILE};
+
+/// Words from most to least common, with their frequencies
+const WORDS: &str = "crane 900\nhyper 800\nslate 700\nplumb 600\nvoxel 5\nqajaq 4\nxylyl 3\nzygon 2\n";
+
+/// Writes a manifest for a dictionary with the given word list, returning its dictionary
+fn frequency_dictionary(test: &str, words: &str) -> Dictionary {
+    let dir = std::env::temp_dir().join(format!("tui-wordle-difficulty-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("words.txt"), words).unwrap();
+
+    let path: PathBuf = dir.join(MANIFEST_FILE);
+    fs::write(&path, "[[dictionary]]\nname = \"Frequent\"\npath = \"words.txt\"\nlengths = [5]\n").unwrap();
+
+    Manifest::load(&path).unwrap().dictionaries(&path).unwrap().remove(0)
+}
+
+/// Gets the answers of the games started with the given difficulty and seeds 0 to 99
+fn answers(dictionary: &Dictionary, difficulty: Difficulty) -> Vec<String> {
+    let dictionary = Arc::new(dictionary.clone());
+
+    (0..100)
+        .map(|seed| {
+            let options = GameOptions {
+                word_length: 5,
+                dictionary: Arc::clone(&dictionary),
+                seed: Some(seed),
+                difficulty,
+                ..GameOptions::default()
+            };
+            GameData::new(&options).answer
+        })
+        .collect()
+}
+
+#[test]
+fn index_reads_the_frequency_column() {
+    let index = WordIndex::new(["crane 12.5", "hyper", "a cat", "ice cream 3", "slate -1"]);
+
+    assert_eq!(index.words(5), ["crane", "hyper"]);
+    assert_eq!(index.frequency("crane"), Some(12.5));
+    assert_eq!(index.frequency("hyper"), None);
+}
+
+#[test]
+fn easy_answers_are_the_most_common_words() {
+    let dictionary = frequency_dictionary("easy", WORDS);
+
+    let easy = answers(&dictionary, Difficulty::Easy);
+    assert!(easy.iter().all(|word| ["crane", "hyper"].contains(&word.as_str())), "{:?}", easy);
+
+    let medium = answers(&dictionary, Difficulty::Medium);
+    assert!(medium.iter().all(|word| ["crane", "hyper", "slate", "plumb"].contains(&word.as_str())), "{:?}", medium);
+}
+
+#[test]
+fn hard_answers_can_be_any_word() {
+    let dictionary = frequency_dictionary("hard", WORDS);
+    let hard = answers(&dictionary, Difficulty::Hard);
+
+    assert!(hard.iter().any(|word| ["voxel", "qajaq", "xylyl", "zygon"].contains(&word.as_str())));
+
+    // Rare words are still accepted as guesses at every difficulty
+    assert!(dictionary.is_valid_word("zygon").unwrap());
+}
+
+#[test]
+fn difficulty_is_ignored_without_frequencies() {
+    let dictionary = frequency_dictionary("none", "crane\nhyper\nslate\nplumb\nvoxel\n");
+
+    assert_eq!(answers(&dictionary, Difficulty::Easy), answers(&dictionary, Difficulty::Hard));
+    assert!(!dictionary.has_frequencies().unwrap());
+    assert!(frequency_dictionary("some", WORDS).has_frequencies().unwrap());
+}
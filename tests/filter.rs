@@ -0,0 +1,66 @@
+//! Tests for filtering words by the results of previous guesses
+
+use proptest::prelude::*;
+use tui_wordle::filter::{Constraint, WordFilter};
+use tui_wordle::game::{score_guess, GameOptions};
+use tui_wordle::solver::is_consistent;
+
+fn chars(word: &str) -> Vec<char> {
+    word.chars().collect()
+}
+
+/// Builds the constraint given by guessing `guess` when the answer is `answer`
+fn constraint(answer: &[char], guess: &[char]) -> Constraint {
+    (guess.to_vec(), score_guess(answer, guess))
+}
+
+#[test]
+fn dictionary_filter_keeps_only_consistent_words() {
+    let options = GameOptions::default();
+    let answer = chars("HYPER");
+    let constraints = vec![constraint(&answer, &chars("CRANE")), constraint(&answer, &chars("MOIST"))];
+
+    let words = options.dictionary.filter_words(&constraints).unwrap();
+
+    assert!(words.contains(&String::from("HYPER")));
+    assert!(words.len() < options.dictionary.words().unwrap().len());
+    for word in &words {
+        assert!(is_consistent(&chars(word), &constraints), "{} is not consistent", word);
+    }
+}
+
+#[test]
+fn no_constraints_match_every_word() {
+    let options = GameOptions::default();
+
+    assert_eq!(
+        options.dictionary.filter_words(&[]).unwrap(),
+        options.dictionary.words().unwrap()
+    );
+}
+
+fn word(len: usize) -> impl Strategy<Value = Vec<char>> {
+    prop::collection::vec(prop::sample::select(vec!['A', 'B', 'C', 'D']), len)
+}
+
+/// Generates a hidden answer, guesses made against it and another word to check
+fn game() -> impl Strategy<Value = (Vec<char>, Vec<Vec<char>>, Vec<char>)> {
+    (1usize..7).prop_flat_map(|len| {
+        (word(len), prop::collection::vec(word(len), 0..4), word(len))
+    })
+}
+
+proptest! {
+    #[test]
+    fn filter_agrees_with_scoring((answer, guesses, candidate) in game()) {
+        let constraints: Vec<Constraint> = guesses
+            .iter()
+            .map(|guess| constraint(&answer, guess))
+            .collect();
+
+        let filter = WordFilter::new(&constraints);
+
+        prop_assert!(filter.matches(&answer));
+        prop_assert_eq!(filter.matches(&candidate), is_consistent(&candidate, &constraints));
+    }
+}
@@ -0,0 +1,145 @@
+//! Tests for dictionaries declared in a manifest file
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tui_wordle::dictionary::{Difficulty, KeyboardLayout};
+use tui_wordle::game::{GameData, GameError, GameOptions};
+use tui_wordle::manifest::{Manifest, ManifestError, MANIFEST_FILE};
+
+/// Writes a manifest and a German word list to a new directory, returning the manifest path
+fn write_manifest(test: &str, manifest: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tui-wordle-manifest-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    fs::write(dir.join("german.txt"), "straße\nhallo\nwürze\nhaus\nzwölf\nquark\n").unwrap();
+    fs::write(dir.join("extra.txt"), "abcde\n").unwrap();
+
+    let path = dir.join(MANIFEST_FILE);
+    fs::write(&path, manifest).unwrap();
+    path
+}
+
+const GERMAN: &str = r#"
+[[dictionary]]
+name = "German"
+path = "german.txt"
+lengths = [4, 5]
+allowed_guesses = "extra.txt"
+alphabet = "ABCDEFGHIJKLMNOPRSTUVWXYZÄÖÜß"
+keyboard = "qwertz"
+locale = "de"
+"#;
+
+#[test]
+fn manifest_declares_a_dictionary_for_each_length() {
+    let path = write_manifest("lengths", GERMAN);
+    let dictionaries = Manifest::load(&path).unwrap().dictionaries(&path).unwrap();
+
+    let lengths: Vec<u8> = dictionaries.iter().map(|d| d.length).collect();
+    assert_eq!(lengths, vec![4, 5]);
+
+    let german = &dictionaries[1];
+    assert_eq!(german.name, "German");
+    assert_eq!(german.keyboard, KeyboardLayout::Qwertz);
+    assert_eq!(german.locale.as_deref(), Some("de"));
+
+    // Words with letters outside the alphabet, such as Q, are skipped
+    assert_eq!(german.words().unwrap(), vec!["HALLO", "WÜRZE", "ZWÖLF"]);
+    assert!(german.is_valid_word("abcde").unwrap());
+    assert_eq!(dictionaries[0].words().unwrap(), vec!["HAUS"]);
+}
+
+#[test]
+fn letters_outside_the_alphabet_are_rejected() {
+    let path = write_manifest("alphabet", GERMAN);
+    let dictionaries = Manifest::load(&path).unwrap().dictionaries(&path).unwrap();
+
+    let options = GameOptions {
+        word_length: 5,
+        dictionary: Arc::new(dictionaries[1].clone()),
+        seed: Some(1),
+        ..GameOptions::default()
+    };
+    let mut game = GameData::new(&options);
+
+    assert!(matches!(game.add_letter('q'), Err(GameError::LetterNotInAlphabet('Q'))));
+    assert!(game.add_letter('ü').is_ok());
+}
+
+#[test]
+fn dictionaries_without_lengths_are_rejected() {
+    let path = write_manifest("no-lengths", "[[dictionary]]\nname = \"Empty\"\npath = \"german.txt\"\nlengths = []\n");
+    let result = Manifest::load(&path).unwrap().dictionaries(&path);
+
+    assert!(matches!(result, Err(ManifestError::NoLengths { .. })));
+}
+
+#[test]
+fn lengths_that_cannot_be_played_are_rejected() {
+    for length in [0, 32] {
+        let manifest = format!("[[dictionary]]\nname = \"German\"\npath = \"german.txt\"\nlengths = [5, {}]\n", length);
+        let path = write_manifest(&format!("length-{}", length), &manifest);
+        let result = Manifest::load(&path).unwrap().dictionaries(&path);
+
+        assert!(matches!(result, Err(ManifestError::InvalidLength { length: l, .. }) if l == length));
+    }
+}
+
+#[test]
+fn lengths_without_words_are_rejected() {
+    let path = write_manifest("no-words", "[[dictionary]]\nname = \"German\"\npath = \"german.txt\"\nlengths = [5, 7]\n");
+    let result = Manifest::load(&path).unwrap().dictionaries(&path);
+
+    assert!(matches!(result, Err(ManifestError::NoWords { length: 7, .. })));
+}
+
+#[test]
+fn invalid_manifest_reports_its_path() {
+    let path = write_manifest("invalid", "[[dictionary]]\nname = 5\n");
+
+    match Manifest::load(&path) {
+        Err(e @ ManifestError::ParseError { .. }) => assert!(e.to_string().contains(&path.display().to_string())),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn answers_come_from_the_answer_list_while_the_word_list_is_still_accepted() {
+    let path = write_manifest(
+        "answers",
+        "[[dictionary]]\nname = \"German\"\npath = \"german.txt\"\nanswers = \"common.txt\"\nlengths = [5]\n",
+    );
+    fs::write(path.parent().unwrap().join("common.txt"), "hallo\n").unwrap();
+
+    let dictionaries = Manifest::load(&path).unwrap().dictionaries(&path).unwrap();
+    let german = &dictionaries[0];
+
+    assert_eq!(german.words().unwrap(), vec!["HALLO"]);
+    assert_eq!(german.guess_words().unwrap(), vec!["HALLO", "WÜRZE", "ZWÖLF", "QUARK"]);
+    assert!(german.is_valid_word("quark").unwrap());
+    assert_eq!(german.random_word(Difficulty::Hard).unwrap(), "hallo");
+}
+
+#[test]
+fn word_lists_load_from_a_relative_manifest_directory() {
+    let path = write_manifest("relative", GERMAN);
+    let cwd = std::env::current_dir().unwrap();
+    let relative = relative_to(&path, &cwd);
+    assert!(relative.is_relative());
+
+    let dictionaries = Manifest::load(&relative).unwrap().dictionaries(&relative).unwrap();
+
+    assert_eq!(dictionaries[1].words().unwrap(), vec!["HALLO", "WÜRZE", "ZWÖLF"]);
+}
+
+/// Gets a path relative to `base` that leads to the same place as `path`
+fn relative_to(path: &std::path::Path, base: &std::path::Path) -> PathBuf {
+    let common = path.components().zip(base.components()).take_while(|(a, b)| a == b).count();
+
+    base.components()
+        .skip(common)
+        .map(|_| std::path::Component::ParentDir.as_os_str())
+        .chain(path.components().skip(common).map(|c| c.as_os_str()))
+        .collect()
+}
@@ -0,0 +1,96 @@
+//! Tests for games played on several boards at once
+
+use tui_wordle::game::{GameError, GameOptions, GameState};
+use tui_wordle::multi::{guess_budget, MultiGame};
+
+/// Creates a seeded game with one board per answer in `answers`
+fn game_with_answers(answers: &[&str]) -> MultiGame {
+    let options = GameOptions {
+        seed: Some(7),
+        boards: answers.len() as u8,
+        ..GameOptions::default()
+    };
+
+    let mut game = MultiGame::new(&options);
+    for (board, answer) in game.boards.iter_mut().zip(answers) {
+        board.answer = answer.to_string();
+    }
+    game
+}
+
+fn guess(game: &mut MultiGame, word: &str) -> Result<GameState, GameError> {
+    for c in word.chars() {
+        game.add_letter(c)?;
+    }
+    game.submit_word()
+}
+
+#[test]
+fn each_board_has_a_different_answer() {
+    for boards in [2, 4, 8] {
+        let options = GameOptions {
+            seed: Some(1),
+            boards,
+            ..GameOptions::default()
+        };
+
+        let game = MultiGame::new(&options);
+        let mut answers: Vec<&str> = game.boards.iter().map(|board| board.answer.as_str()).collect();
+        answers.sort();
+        answers.dedup();
+
+        assert_eq!(answers.len(), boards as usize);
+    }
+}
+
+#[test]
+fn boards_get_an_extended_guess_budget() {
+    assert_eq!(guess_budget(6, 1), 6);
+    assert_eq!(guess_budget(6, 2), 7);
+    assert_eq!(guess_budget(6, 4), 9);
+    assert_eq!(guess_budget(6, 8), 13);
+
+    let game = game_with_answers(&["HYPER", "CRANE", "MOIST", "CLOUD"]);
+    assert!(game.boards.iter().all(|board| board.options().max_guesses == 9));
+}
+
+#[test]
+fn solved_boards_stop_taking_guesses() {
+    let mut game = game_with_answers(&["HYPER", "CRANE"]);
+
+    assert_eq!(guess(&mut game, "hyper").unwrap(), GameState::Active);
+    assert_eq!(guess(&mut game, "moist").unwrap(), GameState::Active);
+
+    assert_eq!(game.boards[0].game_state, GameState::Won);
+    assert_eq!(game.boards[0].guesses_used(), 1);
+    assert_eq!(game.boards[1].guesses_used(), 2);
+}
+
+#[test]
+fn solving_every_board_wins() {
+    let mut game = game_with_answers(&["HYPER", "CRANE"]);
+
+    guess(&mut game, "crane").unwrap();
+    assert_eq!(guess(&mut game, "hyper").unwrap(), GameState::Won);
+}
+
+#[test]
+fn running_out_of_guesses_loses() {
+    let mut game = game_with_answers(&["HYPER", "CRANE"]);
+
+    guess(&mut game, "hyper").unwrap();
+    for word in ["moist", "cloud", "badge", "swift", "plumb"] {
+        assert_eq!(guess(&mut game, word).unwrap(), GameState::Active);
+    }
+
+    assert_eq!(guess(&mut game, "light").unwrap(), GameState::Lost);
+    assert!(matches!(guess(&mut game, "crane"), Err(GameError::NoActiveGame)));
+}
+
+#[test]
+fn rejected_guess_changes_no_board() {
+    let mut game = game_with_answers(&["HYPER", "CRANE"]);
+
+    assert!(matches!(guess(&mut game, "aeiou"), Err(GameError::UnknownWord(_))));
+    assert!(game.boards.iter().all(|board| board.guesses_used() == 0));
+}
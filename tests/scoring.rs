@@ -0,0 +1,130 @@
+//! Tests for `score_guess`, covering tricky duplicate-letter cases and general properties
+
+use proptest::prelude::*;
+use tui_wordle::game::score_guess;
+use tui_wordle::game::LetterResult::{self, Absent, Correct, Present};
+
+fn chars(word: &str) -> Vec<char> {
+    word.chars().collect()
+}
+
+fn score(answer: &str, guess: &str) -> Vec<LetterResult> {
+    score_guess(&chars(answer), &chars(guess))
+}
+
+#[test]
+fn known_duplicate_letter_cases() {
+    let cases = [
+        // Exact match
+        ("CRANE", "CRANE", vec![Correct, Correct, Correct, Correct, Correct]),
+        // No letters in common
+        ("CRANE", "MOIST", vec![Absent, Absent, Absent, Absent, Absent]),
+        // A correct letter uses up the only copy, so earlier copies are absent
+        ("THOSE", "GEESE", vec![Absent, Absent, Absent, Correct, Correct]),
+        // Only the first of two guessed copies is present when the answer has one
+        ("ABIDE", "SPEED", vec![Absent, Absent, Present, Absent, Present]),
+        // Both guessed copies are present when the answer has two elsewhere
+        ("SPEED", "ABIDE", vec![Absent, Absent, Absent, Present, Present]),
+        ("HELLO", "LLAMA", vec![Present, Present, Absent, Absent, Absent]),
+        // Correct and present copies of the same letter in one guess
+        ("ROBOT", "FLOOR", vec![Absent, Absent, Present, Correct, Present]),
+        ("ABBEY", "KEBAB", vec![Absent, Present, Correct, Present, Present]),
+        ("EERIE", "EVERY", vec![Correct, Absent, Present, Present, Absent]),
+        // Three guessed copies against two in the answer
+        ("ALLOW", "LOLLY", vec![Present, Present, Correct, Absent, Absent]),
+        // Longer words
+        ("LETTERS", "SETTLER", vec![Present, Correct, Correct, Correct, Present, Present, Present]),
+        // Unicode letters
+        ("ÉTÉ", "ÉÉT", vec![Correct, Present, Present]),
+        ("ÇA", "AÇ", vec![Present, Present]),
+    ];
+
+    for (answer, guess, expected) in cases {
+        assert_eq!(score(answer, guess), expected, "answer {} guess {}", answer, guess);
+    }
+}
+
+#[test]
+fn guess_longer_than_answer() {
+    assert_eq!(score("AB", "ABA"), vec![Correct, Correct, Absent]);
+    assert_eq!(score("AB", "BAB"), vec![Present, Present, Absent]);
+}
+
+/// Generates words over a small alphabet, including a non-ASCII letter, so that
+/// duplicate letters are common
+fn word(len: usize) -> impl Strategy<Value = Vec<char>> {
+    prop::collection::vec(prop::sample::select(vec!['A', 'B', 'C', 'É']), len)
+}
+
+fn answer_and_guess() -> impl Strategy<Value = (Vec<char>, Vec<char>)> {
+    (1usize..10).prop_flat_map(|len| (word(len), word(len)))
+}
+
+fn count(letters: &[char], letter: char) -> usize {
+    letters.iter().filter(|c| **c == letter).count()
+}
+
+proptest! {
+    #[test]
+    fn one_result_per_guess_letter((answer, guess) in answer_and_guess()) {
+        prop_assert_eq!(score_guess(&answer, &guess).len(), guess.len());
+    }
+
+    #[test]
+    fn correct_exactly_where_letters_match((answer, guess) in answer_and_guess()) {
+        let result = score_guess(&answer, &guess);
+
+        for i in 0..guess.len() {
+            prop_assert_eq!(result[i] == Correct, guess[i] == answer[i]);
+        }
+    }
+
+    #[test]
+    fn hints_never_exceed_answer_count((answer, guess) in answer_and_guess()) {
+        let result = score_guess(&answer, &guess);
+
+        for &letter in &guess {
+            let hints = guess
+                .iter()
+                .zip(&result)
+                .filter(|(c, r)| **c == letter && matches!(r, Correct | Present))
+                .count();
+
+            prop_assert!(hints <= count(&answer, letter));
+        }
+    }
+
+    #[test]
+    fn hints_match_shared_letter_count((answer, guess) in answer_and_guess()) {
+        let result = score_guess(&answer, &guess);
+
+        for &letter in &guess {
+            let hints = guess
+                .iter()
+                .zip(&result)
+                .filter(|(c, r)| **c == letter && matches!(r, Correct | Present))
+                .count();
+
+            prop_assert_eq!(hints, count(&answer, letter).min(count(&guess, letter)));
+        }
+    }
+
+    #[test]
+    fn present_letters_are_claimed_left_to_right((answer, guess) in answer_and_guess()) {
+        let result = score_guess(&answer, &guess);
+
+        // An absent copy of a letter is never followed by a present copy of it
+        for i in 0..guess.len() {
+            for j in i + 1..guess.len() {
+                if guess[i] == guess[j] && result[i] == Absent {
+                    prop_assert_ne!(result[j], Present);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn guessing_the_answer_is_all_correct(answer in (1usize..10).prop_flat_map(word)) {
+        prop_assert!(score_guess(&answer, &answer).iter().all(|r| *r == Correct));
+    }
+}
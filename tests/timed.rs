@@ -0,0 +1,125 @@
+//! Tests for the timed game modes and speed runs
+
+use std::time::{Duration, Instant};
+use tui_wordle::game::{GameData, GameOptions, GameState};
+use tui_wordle::multi::MultiGame;
+use tui_wordle::stats::Stats;
+use tui_wordle::timed::{format_clock, next_choice, SpeedRun, TIME_LIMITS};
+
+fn timed_game(time_limit: Option<u64>, guess_time_limit: Option<u64>) -> GameData {
+    let options = GameOptions {
+        seed: Some(3),
+        time_limit: time_limit.map(Duration::from_secs),
+        guess_time_limit: guess_time_limit.map(Duration::from_secs),
+        ..GameOptions::default()
+    };
+
+    let mut game = GameData::new(&options);
+    game.answer = String::from("HYPER");
+    game
+}
+
+fn guess(game: &mut GameData, word: &str) -> GameState {
+    for c in word.chars() {
+        game.add_letter(c).unwrap();
+    }
+    game.submit_word().unwrap()
+}
+
+#[test]
+fn game_is_lost_when_the_countdown_runs_out() {
+    let mut game = timed_game(Some(60), None);
+    let now = Instant::now();
+
+    assert_eq!(game.check_time(now).unwrap(), GameState::Active);
+    assert!(game.time_remaining(now).unwrap() <= Duration::from_secs(60));
+
+    assert_eq!(game.check_time(now + Duration::from_secs(61)).unwrap(), GameState::Lost);
+    assert!(game.timed_out());
+}
+
+#[test]
+fn guess_clock_restarts_after_each_guess() {
+    let mut game = timed_game(None, Some(10));
+
+    guess(&mut game, "crane");
+    let remaining = game.guess_time_remaining(Instant::now()).unwrap();
+    assert!(remaining > Duration::from_secs(9));
+
+    assert_eq!(game.check_time(Instant::now() + Duration::from_secs(11)).unwrap(), GameState::Lost);
+}
+
+#[test]
+fn clock_stops_when_the_game_finishes() {
+    let mut game = timed_game(Some(60), None);
+
+    assert_eq!(guess(&mut game, "hyper"), GameState::Won);
+    let later = Instant::now() + Duration::from_secs(120);
+
+    assert!(game.elapsed(later) < Duration::from_secs(60));
+    assert_eq!(game.check_time(later).unwrap(), GameState::Won);
+    assert!(!game.timed_out());
+}
+
+#[test]
+fn untimed_games_never_run_out() {
+    let mut game = timed_game(None, None);
+    let later = Instant::now() + Duration::from_secs(3600);
+
+    assert_eq!(game.time_remaining(later), None);
+    assert_eq!(game.check_time(later).unwrap(), GameState::Active);
+}
+
+#[test]
+fn speed_run_finishes_after_every_word() {
+    let start = Instant::now();
+    let mut run = SpeedRun::new(2, start);
+
+    assert!(!run.record_win(start + Duration::from_secs(20)));
+    assert!(run.record_win(start + Duration::from_secs(45)));
+    assert_eq!(run.elapsed(start + Duration::from_secs(90)), Duration::from_secs(45));
+}
+
+#[test]
+fn leaderboard_is_fastest_first_per_dictionary() {
+    let mut stats = Stats::default();
+    stats.record_time("Wordle", 5, 1, Duration::from_secs(40));
+    stats.record_time("Wordle", 5, 1, Duration::from_secs(25));
+    stats.record_time("Wordle", 5, 3, Duration::from_secs(90));
+    stats.record_time("French", 5, 1, Duration::from_secs(10));
+
+    let times: Vec<(u16, u64)> = stats
+        .best_times("Wordle", 5, 5)
+        .iter()
+        .map(|t| (t.words, t.time().as_secs()))
+        .collect();
+
+    assert_eq!(times, vec![(1, 25), (1, 40), (3, 90)]);
+    assert_eq!(stats.best_times("Wordle", 5, 1).len(), 2);
+}
+
+#[test]
+fn choices_cycle_through_off() {
+    assert_eq!(next_choice(None, &TIME_LIMITS), Some(60));
+    assert_eq!(next_choice(Some(60), &TIME_LIMITS), Some(120));
+    assert_eq!(next_choice(Some(300), &TIME_LIMITS), None);
+    assert_eq!(format_clock(Duration::from_secs(65)), "1:05");
+}
+
+#[test]
+fn every_board_is_lost_when_a_multi_board_game_runs_out_of_time() {
+    let options = GameOptions {
+        seed: Some(3),
+        boards: 4,
+        time_limit: Some(Duration::from_secs(60)),
+        ..GameOptions::default()
+    };
+    let mut game = MultiGame::new(&options);
+    let start = Instant::now();
+
+    assert_eq!(game.check_time(start).unwrap(), GameState::Active);
+    assert_eq!(game.check_time(start + Duration::from_secs(61)).unwrap(), GameState::Lost);
+
+    assert!(game.timed_out());
+    assert!(game.boards.iter().all(|board| board.game_state == GameState::Lost));
+}
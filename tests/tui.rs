@@ -0,0 +1,531 @@
+//! Headless end-to-end tests driving the TUI through ratatui's `TestBackend`
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::Color;
+use ratatui::Terminal;
+use std::sync::Arc;
+use tui_wordle::config::Config;
+use tui_wordle::dictionary::Difficulty;
+use tui_wordle::game::{GameOptions, GameState};
+use tui_wordle::manifest::{Manifest, MANIFEST_FILE};
+use tui_wordle::stats::GameMode;
+use tui_wordle::tui::event::{AppEvent, EventHandler};
+use tui_wordle::tui::{step, Application, ScreenMode};
+
+const WIDTH: u16 = 100;
+const HEIGHT: u16 = 50;
+
+/// Creates an application with a new seeded game whose answer is `answer`
+fn app_with_answer(answer: &str) -> Application {
+    let options = GameOptions {
+        seed: Some(42),
+        ..GameOptions::default()
+    };
+
+    let mut app = Application::new(options);
+    app.new_game();
+    app.game_state.as_mut().unwrap().answer = answer.to_string();
+    app
+}
+
+fn key(code: KeyCode) -> AppEvent {
+    AppEvent::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn ctrl(c: char) -> AppEvent {
+    AppEvent::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+}
+
+/// Types each letter of `word` without submitting it
+fn type_letters(word: &str) -> Vec<AppEvent> {
+    word.chars().map(|c| key(KeyCode::Char(c))).collect()
+}
+
+/// Types each letter of `word` and submits it
+fn type_word(word: &str) -> Vec<AppEvent> {
+    let mut events = type_letters(word);
+    events.push(key(KeyCode::Enter));
+    events
+}
+
+/// Replays `events` against the application and returns the final rendered screen
+fn run(app: &mut Application, events: Vec<AppEvent>) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    app.events = EventHandler::scripted(events);
+
+    while !app.events.is_finished() && app.app_state != ScreenMode::Quit {
+        step(app, &mut terminal);
+    }
+
+    // Draw once more so the screen reflects the last event
+    step(app, &mut terminal);
+    terminal.backend().buffer().clone()
+}
+
+fn row_text(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect()
+}
+
+/// Gets every row of the screen, joined by new lines
+fn screen_text(buffer: &Buffer) -> String {
+    (0..buffer.area.height).map(|y| row_text(buffer, y)).collect::<Vec<_>>().join("\n")
+}
+
+/// Finds every position on the given rows where `symbol` is drawn
+fn find_symbol(buffer: &Buffer, symbol: &str, rows: std::ops::Range<u16>) -> Vec<(u16, u16)> {
+    rows.flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+        .filter(|pos| buffer[*pos].symbol() == symbol)
+        .collect()
+}
+
+/// Gets the colour of the outline of the grid cell a letter is printed in
+fn grid_cell_colour(buffer: &Buffer, letter: &str) -> Color {
+    let (x, y) = find_symbol(buffer, letter, 1..HEIGHT - 5)[0];
+    buffer[(x, y - 1)].fg
+}
+
+/// Gets the background colour of a key on the on-screen keyboard
+fn keyboard_colour(buffer: &Buffer, letter: &str) -> Color {
+    let (x, y) = find_symbol(buffer, letter, HEIGHT - 5..HEIGHT - 1)[0];
+    buffer[(x, y)].bg
+}
+
+fn has_foreground(buffer: &Buffer, colour: Color) -> bool {
+    buffer.content().iter().any(|cell| cell.fg == colour && cell.symbol() != " ")
+}
+
+#[test]
+fn typed_letters_are_drawn_in_the_grid() {
+    let mut app = app_with_answer("HYPER");
+    let buffer = run(&mut app, type_letters("cra"));
+
+    for letter in ["C", "R", "A"] {
+        assert_eq!(find_symbol(&buffer, letter, 1..HEIGHT - 5).len(), 1, "letter {}", letter);
+    }
+    assert!(find_symbol(&buffer, "N", 1..HEIGHT - 5).is_empty());
+}
+
+#[test]
+fn submitted_guess_colours_grid_and_keyboard() {
+    let mut app = app_with_answer("HYPER");
+    let buffer = run(&mut app, type_word("payer"));
+
+    assert_eq!(grid_cell_colour(&buffer, "P"), Color::LightYellow);
+    assert_eq!(grid_cell_colour(&buffer, "Y"), Color::LightYellow);
+    assert_eq!(grid_cell_colour(&buffer, "E"), Color::LightGreen);
+    assert_eq!(grid_cell_colour(&buffer, "R"), Color::LightGreen);
+
+    assert_eq!(keyboard_colour(&buffer, "P"), Color::LightYellow);
+    assert_eq!(keyboard_colour(&buffer, "E"), Color::LightGreen);
+    assert_eq!(keyboard_colour(&buffer, "A"), Color::DarkGray);
+    assert_eq!(keyboard_colour(&buffer, "Q"), Color::Reset);
+}
+
+#[test]
+fn winning_shows_banner() {
+    let mut app = app_with_answer("HYPER");
+    let buffer = run(&mut app, type_word("hyper"));
+
+    assert_eq!(app.game_state.as_ref().unwrap().game_state, GameState::Won);
+    assert!(has_foreground(&buffer, Color::Green));
+    assert!(row_text(&buffer, HEIGHT - 1).contains("Share: CTRL-Y"));
+}
+
+#[test]
+fn finished_games_show_why_stats_were_not_saved() {
+    let blocker = std::env::temp_dir().join(format!("tui-wordle-finished-blocker-{}", std::process::id()));
+    std::fs::write(&blocker, "").unwrap();
+
+    let mut app = app_with_answer("HYPER");
+    app.stats_path = Some(blocker.join("stats.json"));
+    let buffer = run(&mut app, type_word("hyper"));
+
+    assert_eq!(app.game_state.as_ref().unwrap().game_state, GameState::Won);
+    assert!(screen_text(&buffer).contains("Stats not saved"));
+
+    run(&mut app, vec![ctrl('o'), key(KeyCode::Char('b')), key(KeyCode::Enter)]);
+    let multi = app.multi_state.as_mut().unwrap();
+    multi.boards[0].answer = String::from("HYPER");
+    multi.boards[1].answer = String::from("CRANE");
+    let buffer = run(&mut app, [type_word("hyper"), type_word("crane")].concat());
+
+    assert!(row_text(&buffer, 0).contains("Solved all 2 boards!"));
+    assert!(row_text(&buffer, 0).contains("Stats not saved"));
+}
+
+#[test]
+fn losing_shows_banner() {
+    let mut app = app_with_answer("HYPER");
+    let events = ["crane", "moist", "cloud", "badge", "swift", "plumb"]
+        .iter()
+        .flat_map(|word| type_word(word))
+        .collect();
+    let buffer = run(&mut app, events);
+
+    assert_eq!(app.game_state.as_ref().unwrap().game_state, GameState::Lost);
+    assert!(has_foreground(&buffer, Color::Red));
+    assert!(!has_foreground(&buffer, Color::Green));
+}
+
+#[test]
+fn incomplete_guess_shows_toast_and_highlights_row() {
+    let mut app = app_with_answer("HYPER");
+    let buffer = run(&mut app, type_word("cra"));
+
+    assert!(row_text(&buffer, 0).contains("Guess is incomplete"));
+    assert_eq!(grid_cell_colour(&buffer, "C"), Color::LightRed);
+}
+
+#[test]
+fn unknown_word_shows_toast() {
+    let mut app = app_with_answer("HYPER");
+    let buffer = run(&mut app, type_word("aeiou"));
+
+    assert!(row_text(&buffer, 0).contains("Not in word list: AEIOU"));
+    assert_eq!(app.game_state.as_ref().unwrap().guesses_used(), 0);
+}
+
+#[test]
+fn options_screen_applies_hard_mode() {
+    let mut app = app_with_answer("HYPER");
+    run(&mut app, vec![ctrl('o'), key(KeyCode::Char('h')), key(KeyCode::Enter)]);
+
+    assert_eq!(app.app_state, ScreenMode::Game);
+    assert!(app.game_options.hard_mode);
+}
+
+#[test]
+fn options_screen_cycles_difficulty() {
+    let mut app = app_with_answer("HYPER");
+    run(&mut app, vec![ctrl('o'), key(KeyCode::Char('l')), key(KeyCode::Enter)]);
+
+    assert_eq!(app.game_options.difficulty, Difficulty::Hard);
+}
+
+#[test]
+fn options_screen_shows_when_difficulty_is_unavailable() {
+    // The built-in Wordle list gives no word frequencies
+    let mut app = app_with_answer("HYPER");
+    let buffer = run(&mut app, vec![ctrl('o')]);
+
+    let text = screen_text(&buffer);
+    assert!(text.contains("Unavailable"));
+    assert!(!text.contains("Medium"));
+}
+
+#[test]
+fn options_are_applied_even_if_they_cannot_be_saved() {
+    let mut app = app_with_answer("HYPER");
+    let blocker = std::env::temp_dir().join(format!("tui-wordle-config-blocker-{}", std::process::id()));
+    std::fs::write(&blocker, "").unwrap();
+    app.config_path = Some(blocker.join("config.toml"));
+
+    let buffer = run(&mut app, vec![ctrl('o'), key(KeyCode::Char('h')), key(KeyCode::Enter)]);
+
+    assert_eq!(app.app_state, ScreenMode::Game);
+    assert!(app.game_options.hard_mode);
+    assert!(screen_text(&buffer).contains("Options not saved"));
+}
+
+#[test]
+fn missing_saved_dictionary_is_shown_on_the_first_game_screen() {
+    let mut app = app_with_answer("HYPER");
+    let config = Config {
+        dictionary: String::from("Klingon"),
+        ..Config::from_options(&app.game_options)
+    };
+
+    let e = config.apply(&mut app.game_options).unwrap_err();
+    app.show_notice(e.to_string());
+    let buffer = run(&mut app, vec![]);
+
+    assert!(row_text(&buffer, 0).contains("Saved dictionary 'Klingon' with 5 letter words no longer exists"));
+}
+
+#[test]
+fn options_screen_fits_a_small_terminal() {
+    let mut app = app_with_answer("HYPER");
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    app.events = EventHandler::scripted(vec![ctrl('o')]);
+
+    step(&mut app, &mut terminal);
+    step(&mut app, &mut terminal);
+
+    let text = screen_text(terminal.backend().buffer());
+    for setting in ["Guesses", "Difficulty", "Hard Mode", "Speed Run"] {
+        assert!(text.contains(setting), "{} is not shown", setting);
+    }
+    assert!(row_text(terminal.backend().buffer(), 23).contains("Select: Enter"));
+}
+
+#[test]
+fn options_screen_shows_why_options_were_not_applied() {
+    let dir = std::env::temp_dir().join(format!("tui-wordle-options-error-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("extra.txt"), "hyper\n").unwrap();
+
+    // A dictionary that is not in the list the options screen offers
+    let path = dir.join(MANIFEST_FILE);
+    std::fs::write(&path, "[[dictionary]]\nname = \"Extra\"\npath = \"extra.txt\"\nlengths = [5]\n").unwrap();
+    let dictionary = Manifest::load(&path).unwrap().dictionaries(&path).unwrap().remove(0);
+
+    let mut app = app_with_answer("HYPER");
+    app.game_options.dictionary = Arc::new(dictionary);
+    let buffer = run(&mut app, vec![ctrl('o'), key(KeyCode::Enter)]);
+
+    assert_eq!(app.app_state, ScreenMode::Options);
+    assert!(screen_text(&buffer).contains("Dictionary not found"));
+}
+
+#[test]
+fn escape_quits() {
+    let mut app = app_with_answer("HYPER");
+    run(&mut app, vec![key(KeyCode::Esc)]);
+
+    assert_eq!(app.app_state, ScreenMode::Quit);
+}
+
+#[test]
+fn hint_shows_remaining_words_and_is_recorded() {
+    let mut app = app_with_answer("HYPER");
+    let mut events = type_word("crane");
+    events.push(key(KeyCode::Tab));
+    let buffer = run(&mut app, events);
+
+    assert!(row_text(&buffer, 0).contains("possible words, try"));
+    assert_eq!(app.game_state.as_ref().unwrap().hints_used, 1);
+}
+
+#[test]
+fn remaining_candidates_are_shown_next_to_completed_rows() {
+    let mut app = app_with_answer("HYPER");
+    let buffer = run(&mut app, type_word("crane"));
+
+    let remaining = app.game_state.as_ref().unwrap().remaining_candidates()[0];
+    let label = format!("{} left", remaining);
+    assert!((0..HEIGHT).any(|y| row_text(&buffer, y).contains(&label)));
+}
+
+#[test]
+fn multi_board_game_greys_out_solved_boards() {
+    let mut app = app_with_answer("HYPER");
+    run(&mut app, vec![ctrl('o'), key(KeyCode::Char('b')), key(KeyCode::Enter)]);
+
+    let multi = app.multi_state.as_mut().unwrap();
+    assert_eq!(multi.boards.len(), 2);
+    multi.boards[0].answer = String::from("HYPER");
+    multi.boards[1].answer = String::from("CRANE");
+
+    let buffer = run(&mut app, type_letters("hyp"));
+    assert!(!has_foreground(&buffer, Color::DarkGray));
+
+    let buffer = run(&mut app, type_word("er"));
+    assert!(has_foreground(&buffer, Color::DarkGray));
+    assert_eq!(app.multi_state.as_ref().unwrap().game_state, GameState::Active);
+
+    let buffer = run(&mut app, type_word("crane"));
+    assert_eq!(app.multi_state.as_ref().unwrap().game_state, GameState::Won);
+    assert!(row_text(&buffer, 0).contains("Solved all 2 boards!"));
+
+    // The whole game is recorded once, with the shared guess budget
+    assert_eq!(app.stats.games.len(), 1);
+    assert_eq!(app.stats.games[0].boards, 2);
+    assert_eq!((app.stats.games[0].guesses, app.stats.games[0].max_guesses), (2, 7));
+    assert!(app.last_share.as_ref().unwrap().starts_with("Dordle (Wordle 5) 2/7\n1 2\n"));
+}
+
+#[test]
+fn stats_screen_shows_each_mode_separately() {
+    let mut app = app_with_answer("HYPER");
+    run(&mut app, type_word("hyper"));
+
+    run(&mut app, vec![ctrl('o'), key(KeyCode::Char('b')), key(KeyCode::Enter)]);
+    let multi = app.multi_state.as_mut().unwrap();
+    multi.boards[0].answer = String::from("HYPER");
+    multi.boards[1].answer = String::from("CRANE");
+    run(&mut app, [type_word("hyper"), type_word("crane")].concat());
+
+    // The stats screen opens on the mode being played
+    run(&mut app, vec![ctrl('s')]);
+    assert_eq!(app.stats_mode, GameMode::Boards(2));
+    assert_eq!(app.stats.summary("Wordle", 5, app.stats_mode).played, 1);
+
+    run(&mut app, vec![key(KeyCode::Right)]);
+    assert_eq!(app.stats_mode, GameMode::Classic);
+    assert_eq!(app.stats.summary("Wordle", 5, app.stats_mode).played, 1);
+}
+
+#[test]
+fn speed_run_chains_games_and_records_the_time() {
+    let mut app = app_with_answer("HYPER");
+    app.game_options.speed_run = Some(2);
+    app.new_game();
+    app.game_state.as_mut().unwrap().answer = String::from("HYPER");
+
+    let buffer = run(&mut app, type_word("hyper"));
+    assert_eq!(app.speed_run.as_ref().unwrap().solved, 1);
+    assert_eq!(app.game_state.as_ref().unwrap().game_state, GameState::Active);
+    assert!(row_text(&buffer, HEIGHT - 1).contains("Run 2/2"));
+
+    app.game_state.as_mut().unwrap().answer = String::from("CRANE");
+    run(&mut app, type_word("crane"));
+
+    assert!(app.speed_run.as_ref().unwrap().is_finished());
+    assert_eq!(app.stats.best_times("Wordle", 5, 5)[0].words, 2);
+}
+
+#[test]
+fn speed_run_moves_on_even_if_stats_cannot_be_saved() {
+    let mut app = app_with_answer("HYPER");
+    let blocker = std::env::temp_dir().join(format!("tui-wordle-stats-blocker-{}", std::process::id()));
+    std::fs::write(&blocker, "").unwrap();
+    app.stats_path = Some(blocker.join("stats.json"));
+    app.game_options.speed_run = Some(2);
+    app.new_game();
+    app.game_state.as_mut().unwrap().answer = String::from("HYPER");
+
+    let buffer = run(&mut app, type_word("hyper"));
+
+    assert_eq!(app.speed_run.as_ref().unwrap().solved, 1);
+    assert_eq!(app.game_state.as_ref().unwrap().game_state, GameState::Active);
+    assert!(screen_text(&buffer).contains("Stats not saved"));
+}
+
+#[test]
+fn speed_runs_ignore_daily_mode() {
+    let mut app = app_with_answer("HYPER");
+    app.game_options.daily = true;
+    app.game_options.speed_run = Some(3);
+    app.new_game();
+
+    let game = app.game_state.as_ref().unwrap();
+    assert!(game.daily.is_none());
+    assert!(!game.options().daily);
+}
+
+#[test]
+fn multi_board_speed_run_chains_games_and_records_the_time() {
+    let mut app = app_with_answer("HYPER");
+    app.game_options.boards = 2;
+    app.game_options.speed_run = Some(2);
+    app.new_game();
+
+    for round in 1..=2 {
+        let multi = app.multi_state.as_mut().unwrap();
+        multi.boards[0].answer = String::from("HYPER");
+        multi.boards[1].answer = String::from("CRANE");
+
+        let mut events = type_word("hyper");
+        events.extend(type_word("crane"));
+        let buffer = run(&mut app, events);
+
+        if round == 1 {
+            assert_eq!(app.multi_state.as_ref().unwrap().game_state, GameState::Active);
+            assert!(row_text(&buffer, HEIGHT - 1).contains("Run 2/2"));
+        }
+    }
+
+    assert!(app.speed_run.as_ref().unwrap().is_finished());
+    assert_eq!(app.stats.best_times("Wordle", 5, 5)[0].words, 2);
+}
+
+#[test]
+fn typing_after_moving_the_cursor_overwrites_the_cell() {
+    let mut app = app_with_answer("CRANE");
+    let mut events = type_letters("crone");
+    events.extend([key(KeyCode::Left), key(KeyCode::Left), key(KeyCode::Left)]);
+    events.extend(type_word("a"));
+    run(&mut app, events);
+
+    assert_eq!(app.game_state.as_ref().unwrap().game_state, GameState::Won);
+}
+
+#[test]
+fn cursor_cell_is_highlighted() {
+    let mut app = app_with_answer("HYPER");
+    let mut events = type_letters("cra");
+    events.push(key(KeyCode::Left));
+    let buffer = run(&mut app, events);
+
+    assert_eq!(app.game_state.as_ref().unwrap().cursor(), Some((0, 2)));
+    assert_eq!(grid_cell_colour(&buffer, "A"), Color::LightBlue);
+    assert_ne!(grid_cell_colour(&buffer, "R"), Color::LightBlue);
+}
+
+#[test]
+fn delete_clears_a_cell_and_ctrl_backspace_clears_the_row() {
+    let mut app = app_with_answer("HYPER");
+    let mut events = type_letters("crane");
+    events.extend([key(KeyCode::Left), key(KeyCode::Left), key(KeyCode::Delete)]);
+    let buffer = run(&mut app, events);
+
+    let guess = &app.game_state.as_ref().unwrap().guesses[0];
+    assert_eq!(guess.as_chars(), vec!['C', 'R', 'A', 'E']);
+    assert!(find_symbol(&buffer, "N", 1..HEIGHT - 5).is_empty());
+
+    let events = vec![AppEvent::Key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::CONTROL))];
+    run(&mut app, events);
+
+    let game = app.game_state.as_ref().unwrap();
+    assert!(game.guesses[0].as_chars().is_empty());
+    assert_eq!(game.cursor(), Some((0, 0)));
+}
+
+#[test]
+fn ctrl_h_deletes_a_letter_and_ctrl_u_clears_the_row() {
+    let mut app = app_with_answer("HYPER");
+    let mut events = type_letters("crane");
+    events.push(ctrl('h'));
+    run(&mut app, events);
+
+    assert_eq!(app.game_state.as_ref().unwrap().guesses[0].as_chars(), vec!['C', 'R', 'A', 'N']);
+
+    run(&mut app, vec![ctrl('u')]);
+    assert!(app.game_state.as_ref().unwrap().guesses[0].as_chars().is_empty());
+}
+
+#[test]
+fn typing_past_the_last_cell_fills_the_first_empty_cell() {
+    let mut app = app_with_answer("CRANE");
+    let mut events = type_letters("crone");
+    events.extend([key(KeyCode::Left), key(KeyCode::Left), key(KeyCode::Left), key(KeyCode::Delete)]);
+    events.extend([key(KeyCode::Right), key(KeyCode::Right), key(KeyCode::Right)]);
+    events.extend(type_letters("a"));
+    run(&mut app, events);
+
+    let game = app.game_state.as_ref().unwrap();
+    assert_eq!(game.guesses[0].as_chars(), vec!['C', 'R', 'A', 'N', 'E']);
+    assert!(app.toast.is_none());
+}
+
+#[test]
+fn backspace_on_the_first_cell_reports_nothing_to_delete() {
+    let mut app = app_with_answer("CRANE");
+    let mut events = type_letters("cr");
+    events.extend([key(KeyCode::Left), key(KeyCode::Left), key(KeyCode::Backspace)]);
+    let buffer = run(&mut app, events);
+
+    assert_eq!(app.game_state.as_ref().unwrap().guesses[0].as_chars(), vec!['C', 'R']);
+    assert!(screen_text(&buffer).contains("Nothing to delete before the cursor"));
+}
+
+#[test]
+fn replaying_the_daily_puzzle_is_announced() {
+    let options = GameOptions {
+        daily: true,
+        ..GameOptions::default()
+    };
+    let mut app = Application::new(options);
+    app.new_game();
+    assert!(app.toast.is_none());
+
+    let answer = app.game_state.as_ref().unwrap().answer.clone();
+    run(&mut app, type_word(&answer));
+    assert_eq!(app.stats.games.len(), 1);
+
+    let buffer = run(&mut app, vec![ctrl('n')]);
+
+    assert!(screen_text(&buffer).contains("Daily already played"));
+}
@@ -0,0 +1,53 @@
+//! Tests for indexing word lists by length
+
+use std::sync::Arc;
+use tui_wordle::dictionary::{get_dictionaries, word_index, WordIndex};
+
+#[test]
+fn index_groups_words_by_length_in_letters() {
+    let index = WordIndex::new(["cat", "crane", "hyper", "côte", "straße"]);
+
+    assert_eq!(index.words(3), ["cat"]);
+    assert_eq!(index.words(4), ["côte"]);
+    assert_eq!(index.words(5), ["crane", "hyper"]);
+    assert_eq!(index.words(6), ["straße"]);
+    assert!(index.words(7).is_empty());
+}
+
+#[test]
+fn index_lower_cases_and_removes_duplicates() {
+    let index = WordIndex::new(["Crane", "CRANE", " crane ", "hyper", "Hyper"]);
+
+    assert_eq!(index.words(5), ["crane", "hyper"]);
+}
+
+#[test]
+fn index_skips_entries_that_cannot_be_typed() {
+    let index = WordIndex::new(["", "a cat", "don't", "crane", "x-ray"]);
+
+    assert_eq!(index.words(5), ["crane"]);
+}
+
+#[test]
+fn word_lists_are_indexed_once_and_shared() {
+    let first = word_index("wordle.txt").unwrap();
+    let second = word_index("wordle.txt").unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn dictionary_words_come_from_the_index() {
+    let wordle = get_dictionaries()
+        .into_iter()
+        .find(|d| d.name == "Wordle" && d.length == 5)
+        .unwrap();
+
+    let index = word_index("wordle.txt").unwrap();
+    let words = wordle.words().unwrap();
+
+    assert_eq!(words.len(), index.words(5).len());
+    assert!(wordle.is_valid_word(&index.words(5)[0]).unwrap());
+    assert!(wordle.is_valid_word(&index.words(5)[0].to_uppercase()).unwrap());
+    assert!(!wordle.is_valid_word("zzzzz").unwrap());
+}